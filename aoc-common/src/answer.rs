@@ -0,0 +1,86 @@
+use std::fmt::{self, Display};
+
+/// An answer with its type, for machine-readable output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// A picture, such as letters drawn on a screen, one string per row.
+    Rows(Vec<String>),
+}
+
+impl Answer {
+    /// Name of the type of the answer.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::Text(_) => "text",
+            Answer::Rows(_) => "rows",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => n.fmt(f),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Rows(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+/// Types the parts of a puzzle can answer with.
+pub trait ToAnswer: Display {
+    fn to_answer(&self) -> Answer;
+}
+
+macro_rules! integer_answer {
+    ($($t:ty),*) => {$(
+        impl ToAnswer for $t {
+            fn to_answer(&self) -> Answer {
+                Answer::Integer(*self as i128)
+            }
+        }
+    )*};
+}
+
+integer_answer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl ToAnswer for String {
+    fn to_answer(&self) -> Answer {
+        Answer::Text(self.clone())
+    }
+}
+
+/// Rows of a picture, displayed one per line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rows(pub Vec<String>);
+
+impl Display for Rows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.join("\n"))
+    }
+}
+
+impl ToAnswer for Rows {
+    fn to_answer(&self) -> Answer {
+        Answer::Rows(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers() {
+        assert_eq!(u64::MAX.to_answer(), Answer::Integer(u64::MAX.into()));
+        assert_eq!((-3_i32).to_answer().to_string(), "-3");
+        assert_eq!("CMZ".to_string().to_answer().kind(), "text");
+
+        let rows = Rows(vec!["#.".to_string(), ".#".to_string()]);
+        assert_eq!(rows.to_answer().to_string(), rows.to_string());
+        assert_eq!(rows.to_string(), "#.\n.#");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{rng::Rng, Generate, Solution};
+use std::{env, ops::RangeInclusive};
+
+/// Number of inputs a property is checked on, unless overridden by this variable.
+pub const CASES_VAR: &str = "AOC_CHECK_CASES";
+const CASES: u64 = 64;
+
+// Longest input shown when a property fails.
+const SHOWN: usize = 2000;
+
+/// Checks that `property` holds on the inputs generated for `S`, see `check_with`.
+pub fn check<S: Generate>(
+    sizes: RangeInclusive<usize>,
+    property: impl for<'a> Fn(&S::Input<'a>) -> bool,
+) {
+    check_with::<S>(S::generate, sizes, property)
+}
+
+/// Checks that `property` holds on the inputs `generate` makes from successive seeds, sizes
+/// being drawn from `sizes`.
+///
+/// Panics on the first failing input, or the first one `S` cannot parse, shrunk to the smallest
+/// size that still fails with the same seed.
+pub fn check_with<S: Solution>(
+    generate: impl Fn(&mut Rng, usize) -> String,
+    sizes: RangeInclusive<usize>,
+    property: impl for<'a> Fn(&S::Input<'a>) -> bool,
+) {
+    let cases = env::var(CASES_VAR)
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(CASES);
+
+    // The input if it fails, with the reason why.
+    let fails = |seed: u64, size: usize| {
+        let input = generate(&mut Rng::new(seed), size);
+        let reason = match S::parse(&input) {
+            Ok(parsed) if property(&parsed) => return None,
+            Ok(_) => "property does not hold".to_string(),
+            Err(e) => format!("cannot parse: {e}"),
+        };
+        Some((input, reason))
+    };
+
+    for seed in 0..cases {
+        let size = Rng::new(seed).range(sizes.clone());
+        if fails(seed, size).is_none() {
+            continue;
+        }
+
+        let (size, (input, reason)) = (*sizes.start()..=size)
+            .find_map(|size| Some((size, fails(seed, size)?)))
+            .unwrap();
+        let shown = match input.char_indices().nth(SHOWN) {
+            Some((end, _)) => format!("{}\n...", &input[..end]),
+            None => input,
+        };
+        panic!(
+            "day {}, seed {seed}, size {size}: {reason}\n{shown}",
+            S::DAY
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+    use std::panic;
+
+    struct Words;
+
+    impl Solution for Words {
+        const DAY: u8 = 0;
+
+        type Input<'a> = Vec<&'a str>;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input.split_whitespace().collect())
+        }
+
+        fn part_one(words: &Self::Input<'_>) -> Self::PartOne {
+            words.len()
+        }
+
+        fn part_two(words: &Self::Input<'_>) -> Self::PartTwo {
+            words.len()
+        }
+    }
+
+    fn words(_: &mut Rng, size: usize) -> String {
+        vec!["word"; size].join(" ")
+    }
+
+    #[test]
+    fn holds() {
+        check_with::<Words>(words, 0..=100, |words| words.len() <= 100);
+    }
+
+    #[test]
+    fn shrinks() {
+        let failure = panic::catch_unwind(|| {
+            check_with::<Words>(words, 2..=100, |words| words.len() < 7);
+        })
+        .unwrap_err();
+
+        let message = failure.downcast_ref::<String>().unwrap();
+        assert!(message.contains(", size 7: property does not hold\n"));
+        assert!(message.ends_with("word word word word word word word"));
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+
+/// Error raised by the puzzle input parsers, pointing at the offending text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    day: Option<u8>,
+    line: Option<usize>,
+    column: usize,
+    text: String,
+    reason: String,
+}
+
+// Position of `part` within `text`, as a 1-based column, if `part` is a slice of `text`.
+fn column_of(text: &str, part: &str) -> Option<usize> {
+    let start = text.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).checked_sub(start)?;
+
+    if offset + part.len() <= text.len() && text.is_char_boundary(offset) {
+        Some(text[..offset].chars().count() + 1)
+    } else {
+        None
+    }
+}
+
+impl ParseError {
+    /// Error about `text` as a whole.
+    pub fn new(text: &str, reason: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: None,
+            column: 1,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Error about `part` of `text`, `part` being a slice of `text`.
+    pub fn at(text: &str, part: &str, reason: impl Into<String>) -> Self {
+        Self::new(text, reason).within(text, part)
+    }
+
+    /// Moves an error raised on `part` to the `text` it was sliced from.
+    pub fn within(mut self, text: &str, part: &str) -> Self {
+        if let Some(column) = column_of(text, part) {
+            self.column += column - 1;
+            self.text = text.to_string();
+        }
+        self
+    }
+
+    /// Sets the 1-based line number, unless already known.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Shifts the line number, for errors raised on a block starting `lines` lines into the input.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line = self.line.map(|line| line + lines);
+        self
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "line {line}, ")?;
+        }
+        write!(
+            f,
+            "column {}: {}, in `{}`",
+            self.column, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses every line of `input` with `f`, numbering the lines of its errors.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location() {
+        let line = "addx 1O";
+        let e = ParseError::at(line, &line[5..], "invalid integer")
+            .at_line(4)
+            .in_day(10);
+
+        assert_eq!((e.line(), e.column()), (Some(4), 6));
+        assert_eq!(
+            e.to_string(),
+            "day 10, line 4, column 6: invalid integer, in `addx 1O`"
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let line = "2-4,6-x";
+        let range = &line[4..];
+        let e = ParseError::at(range, &range[2..], "invalid bound")
+            .within(line, range)
+            .at_line(2)
+            .offset_lines(10)
+            .at_line(1);
+
+        assert_eq!((e.line(), e.column(), e.text()), (Some(12), 7, line));
+    }
+
+    #[test]
+    fn unrelated_part() {
+        let text = String::from("abc");
+        let e = ParseError::at(&text, "b", "oops");
+        assert_eq!((e.column(), e.text()), (1, "abc"));
+    }
+
+    #[test]
+    fn lines() {
+        let parsed = parse_lines("1\n2\n3", |line| {
+            line.parse::<u8>()
+                .map_err(|e| ParseError::new(line, e.to_string()))
+        });
+        assert_eq!(parsed, Ok(vec![1, 2, 3]));
+
+        let e = parse_lines("1\nx\n3", |line| {
+            line.parse::<u8>()
+                .map_err(|e| ParseError::new(line, e.to_string()))
+        })
+        .unwrap_err();
+        assert_eq!(e.line(), Some(2));
+    }
+}
@@ -0,0 +1,278 @@
+use crate::rng::Rng;
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+// Characters inserted by the mutations: what the puzzles use, plus a few multi-byte ones to
+// catch slicing in the middle of a character.
+const CHARACTERS: &[&str] = &[
+    "0", "1", "9", "-", "+", " ", "\n", "\t", "$", "[", "]", ",", ".", "/", "a", "z", "A", "Z",
+    "é", "€", "🦀",
+];
+const NUMBERS: &[&str] = &[
+    "0",
+    "1",
+    "-1",
+    "255",
+    "256",
+    "65536",
+    "2147483647",
+    "-2147483648",
+    "4294967296",
+    "18446744073709551616",
+    "99999999999999999999999",
+];
+
+/// A panic of the fuzzed target, with the input that caused it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crash {
+    pub input: String,
+    /// Panic message and location.
+    pub message: String,
+}
+
+impl fmt::Display for Crash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, on input {:?}", self.message, self.input)
+    }
+}
+
+thread_local! {
+    // Message of the last panic caught on this thread, `None` when panics are not caught.
+    static CAUGHT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Keeps the messages of the panics being caught instead of printing them, once for all threads.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let message = match info.payload().downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => info
+                    .payload()
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .unwrap_or_default(),
+            };
+            let location = info
+                .location()
+                .map(|l| format!(" at {}:{}", l.file(), l.line()))
+                .unwrap_or_default();
+
+            let caught = CAUGHT.with(|caught| match caught.borrow_mut().as_mut() {
+                Some(caught) => {
+                    *caught = format!("{message}{location}");
+                    true
+                }
+                None => false,
+            });
+            if !caught {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Runs `target` on `input`, returning the message of its panic if it panics.
+pub fn catch(input: &str, target: &impl Fn(&str)) -> Option<String> {
+    install_hook();
+
+    CAUGHT.with(|caught| *caught.borrow_mut() = Some(String::new()));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| target(input)));
+    let message = CAUGHT.with(|caught| caught.borrow_mut().take().unwrap_or_default());
+
+    result.err().map(|_| message)
+}
+
+fn mutate(rng: &mut Rng, input: &str, corpus: &[String]) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+    let position = |rng: &mut Rng, bytes: &[u8]| rng.range(0..=bytes.len());
+
+    for _ in 0..rng.range(1..=4) {
+        match rng.range(0..=7) {
+            // Delete a few bytes.
+            0 => {
+                let start = position(rng, &bytes);
+                let end = (start + rng.range(1..=8)).min(bytes.len());
+                bytes.drain(start..end);
+            }
+            // Insert a character.
+            1 => {
+                let at = position(rng, &bytes);
+                let c = rng.choose(CHARACTERS).as_bytes();
+                bytes.splice(at..at, c.iter().copied());
+            }
+            // Overwrite a byte.
+            2 if !bytes.is_empty() => {
+                let at = rng.range(0..=bytes.len() - 1);
+                bytes[at] = rng.choose(CHARACTERS).as_bytes()[0];
+            }
+            // Duplicate a chunk.
+            3 => {
+                let start = position(rng, &bytes);
+                let end = (start + rng.range(1..=32)).min(bytes.len());
+                let chunk = bytes[start..end].to_vec();
+                bytes.splice(end..end, chunk);
+            }
+            // Drop or duplicate a line.
+            4 => {
+                let mut lines = bytes.split(|&b| b == b'\n').collect::<Vec<_>>();
+                let line = rng.range(0..=lines.len() - 1);
+                if rng.chance(0.5) {
+                    lines.remove(line);
+                } else {
+                    lines.insert(line, lines[line]);
+                }
+                bytes = lines.join(&b'\n');
+            }
+            // Swap a number for an edge case.
+            5 => {
+                let digits = bytes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, b)| b.is_ascii_digit())
+                    .map(|(i, _)| i)
+                    .collect::<Vec<_>>();
+                if !digits.is_empty() {
+                    let start = *rng.choose(&digits);
+                    let end = bytes[start..]
+                        .iter()
+                        .position(|b| !b.is_ascii_digit())
+                        .map_or(bytes.len(), |len| start + len);
+                    bytes.splice(start..end, rng.choose(NUMBERS).bytes());
+                }
+            }
+            // Splice with another input.
+            6 => {
+                let other = rng.choose(corpus).as_bytes();
+                let at = position(rng, &bytes);
+                bytes.truncate(at);
+                bytes.extend(&other[rng.range(0..=other.len())..]);
+            }
+            // Truncate.
+            _ => {
+                let at = position(rng, &bytes);
+                bytes.truncate(at);
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Shrinks `crash` to a smaller input still panicking the same way, dropping lines then bytes.
+pub fn minimize(crash: &Crash, target: &impl Fn(&str)) -> Crash {
+    let mut input = crash.input.clone();
+    let same_panic = |input: &str| catch(input, target).as_ref() == Some(&crash.message);
+
+    let mut i = 0;
+    while i < input.lines().count() {
+        let candidate = input
+            .lines()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, line)| line)
+            .collect::<Vec<_>>()
+            .join("\n");
+        if same_panic(&candidate) {
+            input = candidate;
+        } else {
+            i += 1;
+        }
+    }
+
+    // Until no single character can be removed.
+    let mut shrunk = true;
+    while shrunk {
+        shrunk = false;
+        let mut i = 0;
+        while i < input.len() {
+            if input.is_char_boundary(i) {
+                let mut candidate = input.clone();
+                candidate.remove(i);
+                if same_panic(&candidate) {
+                    input = candidate;
+                    shrunk = true;
+                    continue;
+                }
+            }
+            i += 1;
+        }
+    }
+
+    Crash {
+        input,
+        message: crash.message.clone(),
+    }
+}
+
+/// Runs `target` on `iterations` mutations of the `corpus` inputs, returning one minimized
+/// crash per distinct panic.
+pub fn fuzz(
+    rng: &mut Rng,
+    corpus: &[String],
+    iterations: usize,
+    target: impl Fn(&str),
+) -> Vec<Crash> {
+    let mut crashes = vec![];
+    let mut seen = HashSet::new();
+
+    let inputs = corpus.iter().cloned().chain((0..iterations).map(|_| {
+        let input = rng.choose(corpus);
+        mutate(rng, input, corpus)
+    }));
+    for input in inputs {
+        if let Some(message) = catch(&input, &target) {
+            if seen.insert(message.clone()) {
+                crashes.push(minimize(&Crash { input, message }, &target));
+            }
+        }
+    }
+
+    crashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(input: &str) {
+        let numbers = input
+            .split_whitespace()
+            .map(|n| n.parse::<u8>().unwrap_or(0))
+            .collect::<Vec<_>>();
+        if numbers.len() > 1 {
+            let _ = numbers[0] + numbers[1];
+        }
+    }
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch("1 2", &target), None);
+        let message = catch("200 100", &target).unwrap();
+        assert!(message.starts_with("attempt to add with overflow at "));
+    }
+
+    #[test]
+    fn minimizes() {
+        let crash = Crash {
+            input: "1 2\n200 100 5\n7".to_string(),
+            message: catch("200 100", &target).unwrap(),
+        };
+        assert_eq!(minimize(&crash, &target).input, "200 100");
+    }
+
+    #[test]
+    fn finds_crashes() {
+        let corpus = ["12 34\n56".to_string()];
+        let crashes = fuzz(&mut Rng::new(0), &corpus, 2000, target);
+        assert_eq!(crashes.len(), 1);
+        assert!(crashes[0].input.split_whitespace().count() >= 2);
+    }
+}
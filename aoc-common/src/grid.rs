@@ -0,0 +1,355 @@
+use crate::{
+    direction::{Dir4, Dir8, Direction},
+    ParseError,
+};
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular grid stored row after row, positions being `(x, y)` with `y` growing downwards.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the given width holding `cells` row after row, `None` if they do not fill it.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Grid of the given size, cell `pos` holding `f(pos)`. Panics on a zero width, as rows
+    /// could not be told apart.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        assert!(width > 0, "grid without columns");
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid of the given size, every cell holding `value`. Panics on a zero width.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width > 0, "grid without columns");
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Reads a grid of characters, one row per line, converting each with `f` or failing with
+    /// the reason it returns.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let error =
+                |part: &str, reason: String| ParseError::at(line, part, reason).at_line(i + 1);
+
+            let start = cells.len();
+            for (j, c) in line.char_indices() {
+                cells.push(f(c).map_err(|reason| error(&line[j..j + c.len_utf8()], reason))?);
+            }
+
+            let row = cells.len() - start;
+            match width {
+                None => width = Some(row),
+                Some(width) if width != row => {
+                    return Err(error(line, format!("row of {row} cells, expected {width}")))
+                }
+                Some(_) => {}
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::from_cells(width, cells).unwrap()),
+            _ => Err(
+                ParseError::new(input.lines().next().unwrap_or_default(), "empty grid").at_line(1),
+            ),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.1 * self.width + pos.0])
+    }
+
+    /// Position one step from `pos` in `dir`, if still on the grid.
+    pub fn step(&self, (x, y): (usize, usize), dir: impl Direction) -> Option<(usize, usize)> {
+        let (dx, dy) = dir.offset();
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Every position, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} out of a grid {} wide",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Cells met going from `pos` in `dir` up to the edge, `pos` excluded.
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        dir: impl Direction,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        std::iter::successors(self.step(pos, dir), move |&pos| self.step(pos, dir))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Cells up, right, down and left of `pos`, when on the grid.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = ((usize, usize), &T)> {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Cells around `pos`, diagonals included, when on the grid.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = ((usize, usize), &T)> {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, rows becoming columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} out of a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} out of a {width}x{height} grid"),
+        }
+    }
+}
+
+/// One line per row, cells side by side.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef", Ok).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(&'f'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digit = |c: char| c.to_digit(10).ok_or(format!("not a digit `{c}`"));
+        let e = Grid::parse("12\n3x", digit).unwrap_err();
+        assert_eq!(
+            (e.line(), e.column(), e.reason()),
+            (Some(2), 2, "not a digit `x`")
+        );
+        let e = Grid::parse("12\n345", digit).unwrap_err();
+        assert_eq!(
+            (e.line(), e.reason()),
+            (Some(2), "row of 3 cells, expected 2")
+        );
+        assert!(Grid::parse("", digit).is_err());
+        assert!(Grid::parse("\n\n", digit).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "grid without columns")]
+    fn from_fn_without_columns() {
+        Grid::from_fn(0, 3, |_| 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid without columns")]
+    fn filled_without_columns() {
+        Grid::filled(0, 3, 0);
+    }
+
+    #[test]
+    fn lines() {
+        let grid = letters();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        let columns = grid
+            .columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn rays() {
+        let grid = Grid::from_fn(4, 4, |(x, y)| x + 4 * y);
+        let ray = |pos, dir: Dir8| grid.ray(pos, dir).map(|(_, &n)| n).collect::<Vec<_>>();
+
+        assert_eq!(ray((1, 1), Dir4::Right.into()), [6, 7]);
+        assert_eq!(ray((1, 1), Dir4::Up.into()), [1]);
+        assert_eq!(ray((0, 0), Dir4::Left.into()), []);
+        assert_eq!(ray((0, 0), Dir8::DownRight), [5, 10, 15]);
+        assert_eq!(ray((0, 3), Dir8::UpRight), [9, 6, 3]);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::from_fn(3, 3, |(x, y)| x + 3 * y);
+        let around = |neighbors: &mut dyn Iterator<Item = ((usize, usize), &usize)>| {
+            let mut cells = neighbors.map(|(_, &n)| n).collect::<Vec<_>>();
+            cells.sort();
+            cells
+        };
+
+        assert_eq!(around(&mut grid.neighbors4((1, 1))), [1, 3, 5, 7]);
+        assert_eq!(around(&mut grid.neighbors4((0, 0))), [1, 3]);
+        assert_eq!(
+            around(&mut grid.neighbors8((1, 1))),
+            [0, 1, 2, 3, 5, 6, 7, 8]
+        );
+        assert_eq!(around(&mut grid.neighbors8((2, 0))), [1, 4, 5]);
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = letters();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+
+        let mut grid = Grid::filled(2, 2, '.');
+        grid[(1, 0)] = '#';
+        assert_eq!(grid.to_string(), ".#\n..");
+        assert_eq!(Grid::from_cells(2, vec![1, 2, 3]), None);
+    }
+}
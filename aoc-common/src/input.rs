@@ -0,0 +1,188 @@
+use std::{
+    env, fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing to a directory of `dayNN.txt` puzzle inputs.
+pub const INPUTS_VAR: &str = "AOC_INPUTS";
+
+/// Where to read the puzzle input of a day from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// A directory holding one `dayNN.txt` file per day.
+    Directory(PathBuf),
+    /// The day crate's own `src/input_data/input.txt`.
+    Default,
+}
+
+impl InputSource {
+    /// Reads a command line argument, `-` standing for the standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::File(PathBuf::from(path)),
+        }
+    }
+
+    /// The inputs directory set in the environment, if any.
+    pub fn from_env() -> Self {
+        match env::var_os(INPUTS_VAR) {
+            Some(dir) if !dir.is_empty() => Self::Directory(PathBuf::from(dir)),
+            _ => Self::Default,
+        }
+    }
+
+    /// The file holding the input of `day`, `None` for the standard input.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None,
+            Self::Directory(dir) => Some(dir.join(format!("day{day:02}.txt"))),
+            Self::Default => Some(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("..")
+                    .join(format!("day{day:02}"))
+                    .join("src/input_data/input.txt"),
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing {
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        day: u8,
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { day, path } => write!(
+                f,
+                "no puzzle input for day {day}: `{}` does not exist \
+                (pass a file, `-` for the standard input, or set {INPUTS_VAR} to a directory of dayNN.txt files)",
+                path.display()
+            ),
+            Self::Io {
+                day,
+                path: Some(path),
+                source,
+            } => write!(f, "cannot read input of day {day} from `{}`: {source}", path.display()),
+            Self::Io {
+                day,
+                path: None,
+                source,
+            } => write!(f, "cannot read input of day {day} from the standard input: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Missing { .. } => None,
+            Self::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Reads the whole puzzle input of `day`.
+pub fn load(day: u8, source: &InputSource) -> Result<String, InputError> {
+    match source.path(day) {
+        Some(path) => fs::read_to_string(&path).map_err(|source| match source.kind() {
+            io::ErrorKind::NotFound => InputError::Missing { day, path },
+            _ => InputError::Io {
+                day,
+                path: Some(path),
+                source,
+            },
+        }),
+        None => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|source| InputError::Io {
+                    day,
+                    path: None,
+                    source,
+                })?;
+            Ok(input)
+        }
+    }
+}
+
+/// Opens the puzzle input of `day`, to be read as it goes.
+pub fn open(day: u8, source: &InputSource) -> Result<Box<dyn BufRead>, InputError> {
+    match source.path(day) {
+        Some(path) => match File::open(&path) {
+            Ok(file) => Ok(Box::new(BufReader::new(file))),
+            Err(source) if source.kind() == io::ErrorKind::NotFound => {
+                Err(InputError::Missing { day, path })
+            }
+            Err(source) => Err(InputError::Io {
+                day,
+                path: Some(path),
+                source,
+            }),
+        },
+        None => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(InputSource::from_arg("-").path(3), None);
+        assert_eq!(
+            InputSource::from_arg("foo.txt").path(3),
+            Some(PathBuf::from("foo.txt"))
+        );
+        assert_eq!(
+            InputSource::Directory(PathBuf::from("inputs")).path(3),
+            Some(PathBuf::from("inputs/day03.txt"))
+        );
+        assert!(InputSource::Default
+            .path(3)
+            .unwrap()
+            .ends_with("day03/src/input_data/input.txt"));
+    }
+
+    #[test]
+    fn missing_input() {
+        let source = InputSource::Directory(PathBuf::from("does/not/exist"));
+        match load(7, &source) {
+            Err(e @ InputError::Missing { .. }) => {
+                assert!(e.to_string().contains("does/not/exist/day07.txt"))
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+
+    #[test]
+    fn load_file() {
+        let source = InputSource::from_arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../day01/src/input_data/test.txt"
+        ));
+        assert!(load(1, &source).unwrap().starts_with("1000\n"));
+
+        let mut line = String::new();
+        open(1, &source).unwrap().read_line(&mut line).unwrap();
+        assert_eq!(line, "1000\n");
+    }
+}
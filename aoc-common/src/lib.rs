@@ -0,0 +1,111 @@
+pub mod answer;
+pub mod check;
+pub mod direction;
+pub mod error;
+pub mod fuzz;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod point;
+pub mod rng;
+pub mod stream;
+
+pub use answer::{Answer, Rows, ToAnswer};
+pub use error::{parse_lines, ParseError};
+
+use input::InputSource;
+use rng::Rng;
+use std::{fmt::Display, io::BufRead, process::ExitCode};
+use stream::StreamError;
+
+/// A day of the calendar: one parsing step shared by both parts of the puzzle.
+pub trait Solution {
+    /// Day of the calendar, starting at 1.
+    const DAY: u8;
+
+    /// Parsed puzzle input, may borrow from the raw text.
+    type Input<'a>;
+    type PartOne: ToAnswer;
+    type PartTwo: ToAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne;
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo;
+}
+
+/// Generator of random puzzle inputs, for stress tests and benchmarks.
+pub trait Generate: Solution {
+    /// Size of a typical puzzle input.
+    const SIZE: usize;
+
+    /// Generates an input `parse` accepts, `size` roughly counting its lines or items.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// A day that can also be solved from a stream, in bounded memory, for inputs too large to be
+/// read whole.
+pub trait Stream: Solution {
+    /// Solves both parts, giving the same answers `parse` and the parts would.
+    fn solve_reader(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), StreamError>;
+}
+
+/// Solves both parts of `S` and prints the answers.
+pub fn run<S: Solution>(input: &str) -> Result<(), ParseError> {
+    let parsed = S::parse(input).map_err(|e| e.in_day(S::DAY))?;
+    println!("Part 1: {}", S::part_one(&parsed));
+    println!("Part 2: {}", S::part_two(&parsed));
+    Ok(())
+}
+
+/// Solves both parts of `S` from a stream and prints the answers.
+pub fn run_stream<S: Stream>(reader: impl BufRead) -> Result<(), StreamError> {
+    let (one, two) = S::solve_reader(reader).map_err(|e| e.in_day(S::DAY))?;
+    println!("Part 1: {one}");
+    println!("Part 2: {two}");
+    Ok(())
+}
+
+// Input named by the first argument, else by the environment.
+fn source() -> InputSource {
+    match std::env::args().nth(1) {
+        Some(arg) => InputSource::from_arg(&arg),
+        None => InputSource::from_env(),
+    }
+}
+
+/// Exit code of a binary ending with `result`, printing the error if any.
+pub fn exit(result: Result<(), impl Display>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Entry point of the day binaries, reading the input from the file given as first argument.
+pub fn main<S: Solution>() -> ExitCode {
+    main_from::<S>(&source())
+}
+
+/// Same as [`main`], for binaries reading their own arguments, with the input they name.
+pub fn main_from<S: Solution>(source: &InputSource) -> ExitCode {
+    let result = input::load(S::DAY, source)
+        .map_err(|e| e.to_string())
+        .and_then(|input| run::<S>(&input).map_err(|e| e.to_string()));
+    exit(result)
+}
+
+/// Same as [`main`], streaming the input instead of reading it whole.
+pub fn main_stream<S: Stream>() -> ExitCode {
+    main_stream_from::<S>(&source())
+}
+
+/// Same as [`main_from`], streaming the input instead of reading it whole.
+pub fn main_stream_from<S: Stream>(source: &InputSource) -> ExitCode {
+    let result = input::open(S::DAY, source)
+        .map_err(|e| e.to_string())
+        .and_then(|reader| run_stream::<S>(reader).map_err(|e| e.to_string()));
+    exit(result)
+}
@@ -0,0 +1,270 @@
+use crate::ParseError;
+use std::{fmt::Display, str::FromStr};
+
+/// Why a parser failed, `at` being what was left of its input there.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub at: &'a str,
+    pub reason: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(at: &'a str, reason: impl Into<String>) -> Self {
+        Self {
+            at,
+            reason: reason.into(),
+        }
+    }
+
+    /// Error about `text`, the input `at` was sliced from.
+    pub fn into_error(self, text: &str) -> ParseError {
+        ParseError::at(text, self.at, self.reason)
+    }
+}
+
+/// What is left of the input after a parser, and the value it parsed.
+pub type PResult<'a, T> = Result<(&'a str, T), Failure<'a>>;
+
+/// Runs `parser` on `text`, which it has to consume whole.
+pub fn finish<'a, T>(
+    text: &'a str,
+    parser: impl Fn(&'a str) -> PResult<'a, T>,
+) -> Result<T, ParseError> {
+    match parser(text) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => Err(ParseError::at(text, rest, format!("unexpected `{rest}`"))),
+        Err(failure) => Err(failure.into_error(text)),
+    }
+}
+
+/// Matches `tag` exactly.
+pub fn tag<'a>(tag: &'static str) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(tag) {
+        Some(rest) => Ok((rest, &input[..tag.len()])),
+        None => Err(Failure::new(input, format!("expected `{tag}`"))),
+    }
+}
+
+/// Matches the longest non-empty run of characters satisfying `predicate`, `what` naming it
+/// in errors.
+pub fn take_while1<'a>(
+    what: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input.find(|c| !predicate(c)).unwrap_or(input.len());
+        match end {
+            0 => Err(Failure::new(input, format!("expected {what}"))),
+            _ => Ok((&input[end..], &input[..end])),
+        }
+    }
+}
+
+/// Non-empty run of non-whitespace characters.
+pub fn word(input: &str) -> PResult<'_, &str> {
+    take_while1("a word", |c| !c.is_whitespace())(input)
+}
+
+/// Spaces or tabs, at least one.
+pub fn space(input: &str) -> PResult<'_, &str> {
+    take_while1("a space", |c| c == ' ' || c == '\t')(input)
+}
+
+/// Everything left, possibly nothing.
+pub fn rest(input: &str) -> PResult<'_, &str> {
+    Ok(("", input))
+}
+
+// Numbers are read up to the next delimiter, so that `12x` is an invalid number rather than
+// `12` followed by something unexpected.
+fn number<T: FromStr>(input: &str, signed: bool) -> PResult<'_, T>
+where
+    T::Err: Display,
+{
+    let sign = usize::from(signed && input.starts_with(['-', '+']));
+    let end = input[sign..]
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .map_or(input.len(), |end| sign + end);
+    if end == sign {
+        return Err(Failure::new(input, "expected a number"));
+    }
+
+    let token = &input[..end];
+    match token.parse() {
+        Ok(n) => Ok((&input[end..], n)),
+        Err(e) => Err(Failure::new(token, format!("invalid number: {e}"))),
+    }
+}
+
+/// Integer without a sign.
+pub fn unsigned<T: FromStr>(input: &str) -> PResult<'_, T>
+where
+    T::Err: Display,
+{
+    number(input, false)
+}
+
+/// Integer with an optional `-` or `+` sign.
+pub fn signed<T: FromStr>(input: &str) -> PResult<'_, T>
+where
+    T::Err: Display,
+{
+    number(input, true)
+}
+
+pub fn map<'a, T, U>(
+    parser: impl Fn(&'a str) -> PResult<'a, T>,
+    f: impl Fn(T) -> U,
+) -> impl Fn(&'a str) -> PResult<'a, U> {
+    move |input| parser(input).map(|(rest, value)| (rest, f(value)))
+}
+
+/// Runs `parser` if it matches, consuming nothing otherwise.
+pub fn opt<'a, T>(
+    parser: impl Fn(&'a str) -> PResult<'a, T>,
+) -> impl Fn(&'a str) -> PResult<'a, Option<T>> {
+    move |input| match parser(input) {
+        Ok((rest, value)) => Ok((rest, Some(value))),
+        Err(_) => Ok((input, None)),
+    }
+}
+
+/// Runs `parser` after `prefix`, keeping what the former parsed.
+pub fn preceded<'a, P, T>(
+    prefix: impl Fn(&'a str) -> PResult<'a, P>,
+    parser: impl Fn(&'a str) -> PResult<'a, T>,
+) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input| {
+        let (input, _) = prefix(input)?;
+        parser(input)
+    }
+}
+
+/// One `item` or more, separated by `separator`.
+pub fn separated<'a, T, S>(
+    item: impl Fn(&'a str) -> PResult<'a, T>,
+    separator: impl Fn(&'a str) -> PResult<'a, S>,
+) -> impl Fn(&'a str) -> PResult<'a, Vec<T>> {
+    move |input| {
+        let (mut input, first) = item(input)?;
+        let mut items = vec![first];
+        while let Ok((rest, _)) = separator(input) {
+            let (rest, next) = item(rest)?;
+            items.push(next);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+/// A `key <separator> value` line, both sides trimmed and the key not empty.
+pub fn key_value<'a>(
+    separator: &'static str,
+) -> impl Fn(&'a str) -> PResult<'a, (&'a str, &'a str)> {
+    move |input: &'a str| {
+        let (key, value) = input
+            .split_once(separator)
+            .ok_or_else(|| Failure::new(input, format!("expected `<key> {separator} <value>`")))?;
+        match key.trim() {
+            "" => Err(Failure::new(input, "missing key")),
+            key => Ok(("", (key, value.trim()))),
+        }
+    }
+}
+
+/// Blocks of lines separated by blank lines, each with the number of lines before it, to
+/// locate its errors with [`ParseError::offset_lines`].
+pub fn blocks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut blocks = vec![];
+    let mut start = None;
+    let mut offset = 0;
+
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((first, start)) = start.take() {
+                blocks.push((first, &input[start..offset]));
+            }
+        } else if start.is_none() {
+            start = Some((i, offset));
+        }
+        offset += line.len();
+    }
+    blocks.extend(start.map(|(first, start)| (first, &input[start..])));
+
+    blocks
+        .into_iter()
+        .map(|(first, block)| (first, block.trim_end_matches(['\n', '\r'])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primitives() {
+        assert_eq!(tag("move")("move 1"), Ok((" 1", "move")));
+        assert_eq!(word("ab cd"), Ok((" cd", "ab")));
+        assert_eq!(space("  x"), Ok(("x", "  ")));
+        assert_eq!(rest("a b"), Ok(("", "a b")));
+        assert_eq!(unsigned::<u8>("42,"), Ok((",", 42)));
+        assert_eq!(signed::<i32>("-42-3"), Ok(("-3", -42)));
+        assert_eq!(signed::<i32>("+7"), Ok(("", 7)));
+
+        assert_eq!(tag("move")("mov").unwrap_err().reason, "expected `move`");
+        assert!(word("").is_err());
+        assert!(space("x").is_err());
+        assert_eq!(unsigned::<u8>(",").unwrap_err().reason, "expected a number");
+        assert!(unsigned::<u8>("-1").is_err());
+        assert!(unsigned::<u8>("256").is_err());
+        assert_eq!(unsigned::<u8>("12x y").unwrap_err().at, "12x");
+        assert!(signed::<i8>("-").is_err());
+    }
+
+    #[test]
+    fn combinators() {
+        let list = separated(unsigned::<u32>, tag(", "));
+        assert_eq!(list("1, 2, 3;"), Ok((";", vec![1, 2, 3])));
+        assert_eq!(list("1;2"), Ok((";2", vec![1])));
+        assert!(list("1, x").is_err());
+
+        let sized = preceded(tag("size "), map(unsigned::<u32>, |n| n * 2));
+        assert_eq!(sized("size 21"), Ok(("", 42)));
+        assert_eq!(opt(tag("-"))("x"), Ok(("x", None)));
+        assert_eq!(opt(tag("-"))("-x"), Ok(("x", Some("-"))));
+
+        let entry = key_value("=");
+        assert_eq!(entry(" rock = A "), Ok(("", ("rock", "A"))));
+        assert_eq!(entry("a=b=c"), Ok(("", ("a", "b=c"))));
+        assert!(entry("rock A").is_err());
+        assert!(entry(" = A").is_err());
+    }
+
+    #[test]
+    fn finishing() {
+        let line = "move 3 from x";
+        let e = finish(line, |s| {
+            let (s, _) = tag("move ")(s)?;
+            let (s, count) = unsigned::<u32>(s)?;
+            let (s, _) = tag(" from ")(s)?;
+            let (s, from) = unsigned::<u32>(s)?;
+            Ok((s, (count, from)))
+        })
+        .unwrap_err();
+        assert_eq!((e.column(), e.text()), (13, line));
+
+        let e = finish("12 ab", unsigned::<u32>).unwrap_err();
+        assert_eq!((e.column(), e.reason()), (3, "unexpected ` ab`"));
+        assert_eq!(finish("12", unsigned::<u32>), Ok(12));
+    }
+
+    #[test]
+    fn blocks_of_lines() {
+        let input = "a\nb\n\n\nc\r\n\nd e\n";
+        assert_eq!(
+            blocks(input).collect::<Vec<_>>(),
+            [(0, "a\nb"), (4, "c"), (6, "d e")]
+        );
+        assert_eq!(blocks("\n\n").count(), 0);
+        assert_eq!(blocks("x").collect::<Vec<_>>(), [(0, "x")]);
+    }
+}
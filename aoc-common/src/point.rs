@@ -0,0 +1,335 @@
+use crate::direction::{Dir4, Dir8, Direction};
+use std::{
+    fmt::{self, Debug},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Signed integers points are made of.
+pub trait Coordinate:
+    Copy
+    + Debug
+    + Default
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {$(
+        impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize);
+
+/// A point, or vector, of the plane, `y` growing downwards as for directions.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point, or vector, of space.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+macro_rules! point {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Coordinate> $point<T> {
+            pub const ORIGIN: Self = Self { $($field: T::ZERO),* };
+
+            pub fn new($($field: T),*) -> Self {
+                Self { $($field),* }
+            }
+
+            /// Sum of the absolute coordinates.
+            pub fn manhattan_length(self) -> T {
+                T::ZERO $(+ self.$field.abs())*
+            }
+
+            /// Largest absolute coordinate.
+            pub fn chebyshev_length(self) -> T {
+                T::ZERO $(.max(self.$field.abs()))*
+            }
+
+            /// Taxicab distance, moving along one axis at a time.
+            pub fn manhattan(self, other: Self) -> T {
+                (other - self).manhattan_length()
+            }
+
+            /// King's move distance, diagonals included.
+            pub fn chebyshev(self, other: Self) -> T {
+                (other - self).chebyshev_length()
+            }
+
+            /// Sign of every coordinate, the step towards the direction of the vector.
+            pub fn signum(self) -> Self {
+                Self { $($field: self.$field.signum()),* }
+            }
+        }
+
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        /// Scaling by a factor.
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: Coordinate> MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<T: Coordinate> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Debug> Debug for $point<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("")$(.field(&self.$field))*.finish()
+            }
+        }
+    };
+}
+
+point!(Point { x, y });
+point!(Point3 { x, y, z });
+
+// Offsets are -1, 0 or 1.
+fn unit<T: Coordinate>(offset: isize) -> T {
+    match offset.signum() {
+        -1 => -T::ONE,
+        0 => T::ZERO,
+        _ => T::ONE,
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    /// Unit vector of a direction.
+    pub fn from_direction(dir: impl Direction) -> Self {
+        let (x, y) = dir.offset();
+        Self::new(unit(x), unit(y))
+    }
+
+    /// Point one step away in `dir`.
+    pub fn step(self, dir: impl Direction) -> Self {
+        self + Self::from_direction(dir)
+    }
+
+    /// The four points sharing a side with this one.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Dir4::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// The eight points around this one, diagonals included.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Dir8::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+}
+
+impl<T: Coordinate> From<Dir4> for Point<T> {
+    fn from(dir: Dir4) -> Self {
+        Self::from_direction(dir)
+    }
+}
+
+impl<T: Coordinate> From<Dir8> for Point<T> {
+    fn from(dir: Dir8) -> Self {
+        Self::from_direction(dir)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RANGE: std::ops::RangeInclusive<i32> = -3..=3;
+
+    fn points() -> impl Iterator<Item = Point<i32>> + Clone {
+        RANGE.flat_map(|x| RANGE.map(move |y| Point::new(x, y)))
+    }
+
+    fn points3() -> impl Iterator<Item = Point3<i32>> + Clone {
+        RANGE.flat_map(|x| RANGE.flat_map(move |y| RANGE.map(move |z| Point3::new(x, y, z))))
+    }
+
+    #[test]
+    fn arithmetic() {
+        for a in points() {
+            for b in points() {
+                assert_eq!(a + b, Point::new(a.x + b.x, a.y + b.y));
+                assert_eq!(a - b, Point::new(a.x - b.x, a.y - b.y));
+                assert_eq!(a + b, b + a);
+                assert_eq!(a - b, -(b - a));
+                assert_eq!(a - b + b, a);
+
+                let mut c = a;
+                c += b;
+                c -= a;
+                assert_eq!(c, b);
+            }
+            for k in RANGE {
+                assert_eq!(a * k, Point::new(a.x * k, a.y * k));
+                let mut c = a;
+                c *= k;
+                assert_eq!(c, a * k);
+            }
+            assert_eq!(-a, a * -1);
+            assert_eq!(a + Point::ORIGIN, a);
+        }
+
+        for a in points3() {
+            for b in points3() {
+                assert_eq!(a + b, Point3::new(a.x + b.x, a.y + b.y, a.z + b.z));
+                assert_eq!(a - b, Point3::new(a.x - b.x, a.y - b.y, a.z - b.z));
+                assert_eq!(a - b + b, a);
+            }
+            assert_eq!(a * 2, a + a);
+            assert_eq!(-a + a, Point3::ORIGIN);
+        }
+    }
+
+    #[test]
+    fn distances() {
+        for a in points() {
+            for b in points() {
+                let (dx, dy) = ((a.x - b.x).abs(), (a.y - b.y).abs());
+                assert_eq!(a.manhattan(b), dx + dy);
+                assert_eq!(a.chebyshev(b), dx.max(dy));
+                assert_eq!(a.manhattan(b), b.manhattan(a));
+                assert_eq!(a.chebyshev(b), b.chebyshev(a));
+                assert!(a.chebyshev(b) <= a.manhattan(b));
+            }
+            assert_eq!(a.chebyshev(Point::ORIGIN), a.chebyshev_length());
+            assert_eq!(a.manhattan(Point::ORIGIN), a.manhattan_length());
+        }
+
+        for a in points3() {
+            let (x, y, z) = (a.x.abs(), a.y.abs(), a.z.abs());
+            assert_eq!(a.manhattan_length(), x + y + z);
+            assert_eq!(a.chebyshev_length(), x.max(y).max(z));
+        }
+    }
+
+    #[test]
+    fn signum() {
+        for a in points() {
+            let s = a.signum();
+            assert_eq!(s, Point::new(a.x.signum(), a.y.signum()));
+            assert!(s.chebyshev_length() <= 1);
+            // Stepping by the sign always gets closer, until reaching the origin.
+            if a != Point::ORIGIN {
+                assert_eq!((a - s).chebyshev_length(), a.chebyshev_length() - 1);
+            }
+        }
+        for a in points3() {
+            assert_eq!(
+                a.signum(),
+                Point3::new(a.x.signum(), a.y.signum(), a.z.signum())
+            );
+        }
+    }
+
+    #[test]
+    fn directions() {
+        for dir in Dir4::ALL {
+            let p = Point::<i32>::from(dir);
+            assert_eq!(p.manhattan_length(), 1);
+            assert_eq!(p, Point::from(Dir8::from(dir)));
+            assert_eq!(Point::from(dir.opposite()), -p);
+        }
+        for dir in Dir8::ALL {
+            let p = Point::<i64>::from(dir);
+            assert_eq!(p.chebyshev_length(), 1);
+            assert_eq!(Point::from(dir.opposite()), -p);
+        }
+
+        assert_eq!(Point::new(2, 5).step(Dir4::Up), Point::new(2, 4));
+        assert_eq!(Point::new(2, 5).step(Dir8::DownLeft), Point::new(1, 6));
+        assert_eq!(
+            Point::new(0, 0)
+                .neighbors4()
+                .map(|p| p.manhattan_length())
+                .sum::<i32>(),
+            4
+        );
+        assert_eq!(
+            Point::<i8>::ORIGIN
+                .neighbors8()
+                .filter(|p| p.chebyshev_length() == 1)
+                .count(),
+            8
+        );
+        assert_eq!(format!("{:?}", Point3::new(1, -2, 3)), "(1, -2, 3)");
+    }
+}
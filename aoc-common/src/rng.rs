@@ -0,0 +1,121 @@
+use std::ops::RangeInclusive;
+
+/// Small seedable pseudo-random generator (SplitMix64), good enough to generate puzzle inputs.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+/// Integers `Rng::range` can draw.
+pub trait Uniform: Copy {
+    fn sample(rng: &mut Rng, low: Self, high: Self) -> Self;
+}
+
+macro_rules! uniform {
+    ($($t:ty),*) => {$(
+        impl Uniform for $t {
+            fn sample(rng: &mut Rng, low: Self, high: Self) -> Self {
+                assert!(low <= high, "empty range {low}..={high}");
+                // Wraps to 0 when the range covers every u64.
+                let span = (high as i128 - low as i128 + 1) as u64;
+                let offset = if span == 0 { rng.next_u64() } else { rng.below(span) };
+                (low as i128 + offset as i128) as $t
+            }
+        }
+    )*};
+}
+
+uniform!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`, `n` being positive.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range 0..0");
+        // Reject the top values that would make the lower ones more likely.
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    /// Uniform in `range`, which must not be empty.
+    pub fn range<T: Uniform>(&mut self, range: RangeInclusive<T>) -> T {
+        T::sample(self, *range.start(), *range.end())
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub fn f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.f64() < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        let draw = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(0);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let x = rng.range(-3..=3_i32);
+            assert!((-3..=3).contains(&x));
+            seen[(x + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        assert_eq!(rng.range(5..=5_u8), 5);
+        rng.range(i64::MIN..=i64::MAX);
+        rng.range(0..=u64::MAX);
+        assert!((0.0..1.0).contains(&rng.f64()));
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = Rng::new(1);
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::ParseError;
+use std::{
+    fmt,
+    io::{self, BufRead},
+};
+
+/// Error raised while solving from a stream: either reading or parsing it failed.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl StreamError {
+    /// Sets the day of a parse error, unless already known.
+    pub fn in_day(self, day: u8) -> Self {
+        match self {
+            Self::Parse(e) => Self::Parse(e.in_day(day)),
+            e => e,
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "cannot read input: {e}"),
+            Self::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+/// Calls `f` on every line of `reader`, as `str::lines` splits them, numbering the lines of
+/// its errors. Only holds one line at a time.
+pub fn lines(
+    mut reader: impl BufRead,
+    mut f: impl FnMut(&str) -> Result<(), ParseError>,
+) -> Result<(), StreamError> {
+    let mut line = String::new();
+    let mut number = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+
+        let text = line.strip_suffix('\n').unwrap_or(&line);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        f(text).map_err(|e| e.at_line(number))?;
+    }
+}
+
+// Length of the UTF-8 sequence starting with `byte`.
+fn utf8_width(byte: u8) -> usize {
+    match byte {
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => 1,
+    }
+}
+
+/// Calls `f` on every character of `reader` until it returns `false`, reading a buffer at a
+/// time rather than a line.
+pub fn chars(mut reader: impl BufRead, mut f: impl FnMut(char) -> bool) -> Result<(), StreamError> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "stream is not valid UTF-8");
+    let mut pending = Vec::with_capacity(4);
+
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            if pending.is_empty() {
+                return Ok(());
+            }
+            return Err(invalid().into());
+        }
+
+        let read = buffer.len();
+        for &byte in buffer {
+            pending.push(byte);
+            if pending.len() < utf8_width(pending[0]) {
+                continue;
+            }
+
+            let c = std::str::from_utf8(&pending)
+                .map_err(|_| invalid())?
+                .chars()
+                .next()
+                .unwrap();
+            pending.clear();
+            if !f(c) {
+                return Ok(());
+            }
+        }
+        reader.consume(read);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufReader, Cursor};
+
+    #[test]
+    fn read_lines() {
+        let mut read = vec![];
+        lines(Cursor::new("a\r\nb\n\nc"), |line| {
+            read.push(line.to_string());
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(read, ["a", "b", "", "c"]);
+
+        let e = lines(Cursor::new("1\n2\nx"), |line| match line.parse::<u8>() {
+            Ok(_) => Ok(()),
+            Err(e) => Err(ParseError::new(line, e.to_string())),
+        })
+        .unwrap_err();
+        match e {
+            StreamError::Parse(e) => assert_eq!(e.line(), Some(3)),
+            e => panic!("unexpected error {e}"),
+        }
+    }
+
+    #[test]
+    fn read_chars() {
+        // A tiny buffer, for characters to straddle two of them.
+        let text = "aé€🦀b";
+        let mut read = String::new();
+        chars(BufReader::with_capacity(2, text.as_bytes()), |c| {
+            read.push(c);
+            true
+        })
+        .unwrap();
+        assert_eq!(read, text);
+
+        let mut read = String::new();
+        chars(Cursor::new("abcd"), |c| {
+            read.push(c);
+            c != 'b'
+        })
+        .unwrap();
+        assert_eq!(read, "ab");
+
+        assert!(chars(Cursor::new(b"a\xe2\x82"), |_| true).is_err());
+        assert!(chars(Cursor::new(b"a\xffb"), |_| true).is_err());
+    }
+}
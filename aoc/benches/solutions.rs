@@ -0,0 +1,123 @@
+//! Times the parsing and both parts of every day, on its example and on a generated input of a
+//! typical size.
+//!
+//! Run with `cargo bench -p aoc -- [--quick] [FILTER]`, FILTER selecting benchmarks by name,
+//! e.g. `day08` or `generated`. Results are written as CSV to `target/aoc-bench.csv`, or to
+//! `$AOC_BENCH_OUTPUT`, and compared with the results of the previous run found there.
+
+use aoc::{
+    bench::{Sampling, Stats},
+    registry,
+};
+use aoc_common::rng::Rng;
+use std::{collections::HashMap, env, fs, path::PathBuf};
+
+const CSV_HEADER: &str =
+    "day,input,phase,samples,iterations,mean_ns,median_ns,min_ns,max_ns,std_dev_ns";
+
+// Previous results by `day/input/phase`, with their median time.
+fn read_baseline(path: &PathBuf) -> HashMap<String, (String, u128)> {
+    let Ok(csv) = fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+
+    csv.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split(',').collect::<Vec<_>>();
+            let median = fields.get(6)?.parse().ok()?;
+            Some((fields.get(..3)?.join("/"), (line.to_string(), median)))
+        })
+        .collect()
+}
+
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+fn main() {
+    let mut sampling = Sampling::DEFAULT;
+    let mut filter = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--quick" => sampling = Sampling::QUICK,
+            // Flags passed along by cargo, such as `--bench`.
+            flag if flag.starts_with('-') => {}
+            name => filter = Some(name.to_string()),
+        }
+    }
+
+    let output = env::var_os("AOC_BENCH_OUTPUT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../target/aoc-bench.csv")
+        });
+    let baseline = read_baseline(&output);
+
+    let mut csv = vec![CSV_HEADER.to_string()];
+
+    for entry in registry::DAYS {
+        let day = entry.day;
+
+        for (kind, input) in [
+            ("example", entry.example.to_string()),
+            (
+                "generated",
+                entry.generate(&mut Rng::new(day.into()), entry.size),
+            ),
+        ] {
+            let name = format!("day{day:02}/{kind}");
+            if filter
+                .as_ref()
+                .is_some_and(|filter| !name.contains(filter.as_str()))
+            {
+                // Keep the previous results of the benchmarks filtered out.
+                csv.extend(PHASES.iter().filter_map(|phase| {
+                    let (line, _) = baseline.get(&format!("{day}/{kind}/{phase}"))?;
+                    Some(line.clone())
+                }));
+                continue;
+            }
+
+            let phases = match entry.bench(&input, &sampling) {
+                Ok(phases) => phases,
+                Err(e) => panic!("{name}: {e}"),
+            };
+
+            for (phase, stats) in
+                PHASES
+                    .into_iter()
+                    .zip([phases.parse, phases.part_one, phases.part_two])
+            {
+                let key = format!("{day}/{kind}/{phase}");
+                let change = baseline
+                    .get(&key)
+                    .map(|&(_, previous)| {
+                        let change = stats.median.as_nanos() as f64 / previous.max(1) as f64 - 1.0;
+                        format!("{:+.1}%", change * 100.0)
+                    })
+                    .unwrap_or_default();
+
+                println!("{:<24} {stats}  {change}", format!("{name}/{phase}"));
+                csv.push(csv_row(day, kind, phase, &stats));
+            }
+        }
+    }
+
+    if let Some(dir) = output.parent() {
+        fs::create_dir_all(dir).expect("cannot create the benchmark output directory");
+    }
+    fs::write(&output, csv.join("\n") + "\n").expect("cannot write the benchmark results");
+    println!("\nResults written to {}", output.display());
+}
+
+fn csv_row(day: u8, kind: &str, phase: &str, stats: &Stats) -> String {
+    format!(
+        "{day},{kind},{phase},{},{},{},{},{},{},{}",
+        stats.samples,
+        stats.iterations,
+        stats.mean.as_nanos(),
+        stats.median.as_nanos(),
+        stats.min.as_nanos(),
+        stats.max.as_nanos(),
+        stats.std_dev.as_nanos()
+    )
+}
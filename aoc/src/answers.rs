@@ -0,0 +1,274 @@
+use crate::registry::{Part, Report};
+use aoc_common::ParseError;
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Default answers file, at the root of the workspace.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.toml")
+}
+
+/// Known answers by day and part, stored as a small TOML file:
+///
+/// ```toml
+/// [day01]
+/// part1 = "24000"
+/// part2 = "45000"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u8, BTreeMap<u8, String>>,
+}
+
+fn unescape(s: &str) -> Result<String, ParseError> {
+    let mut output = String::new();
+    let mut chars = s.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some((_, 'n')) => output.push('\n'),
+            Some((_, 't')) => output.push('\t'),
+            Some((_, '"')) => output.push('"'),
+            Some((_, '\\')) => output.push('\\'),
+            _ => return Err(ParseError::at(s, &s[i..], "invalid escape sequence")),
+        }
+    }
+
+    Ok(output)
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let error =
+                |part: &str, reason: &str| ParseError::at(line, part, reason).at_line(i + 1);
+            let content = line.trim();
+
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = content.strip_prefix('[') {
+                let name = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| error(content, "expected `[dayNN]`"))?;
+                day = match name.trim().strip_prefix("day").map(str::parse::<u8>) {
+                    Some(Ok(day)) => Some(day),
+                    _ => return Err(error(name, "expected a day such as `day07`")),
+                };
+                continue;
+            }
+
+            let (key, value) = content
+                .split_once('=')
+                .ok_or_else(|| error(content, "expected `partN = \"answer\"`"))?;
+            let day = day.ok_or_else(|| error(content, "answer outside of a `[dayNN]` table"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(error(key, "expected `part1` or `part2`")),
+            };
+
+            let value = value.trim();
+            let answer = if let Some(quoted) = value.strip_prefix('"') {
+                let string = quoted
+                    .strip_suffix('"')
+                    .ok_or_else(|| error(value, "unterminated string"))?;
+                unescape(string).map_err(|e| e.within(line, string).at_line(i + 1))?
+            } else if value.parse::<i64>().is_ok() {
+                value.to_string()
+            } else {
+                return Err(error(value, "expected a string or an integer"));
+            };
+
+            answers.days.entry(day).or_default().insert(part, answer);
+        }
+
+        Ok(answers)
+    }
+
+    /// Reads the answers in `path`, none being known if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(input) => Self::parse(&input).map_err(|e| format!("in `{}`, {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("cannot read `{}`: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.days.get(&day)?.get(&part.number()).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, answer: &str) {
+        self.days
+            .entry(day)
+            .or_default()
+            .insert(part.number(), answer.to_string());
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (day, parts)) in self.days.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[day{day:02}]")?;
+            for (part, answer) in parts {
+                writeln!(f, "part{part} = \"{}\"", escape(answer))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub status: Status,
+}
+
+/// Compares the answers of `reports` with the known ones.
+pub fn verify(answers: &Answers, reports: &[Report]) -> Vec<Check> {
+    reports
+        .iter()
+        .flat_map(|report| {
+            report.parts.iter().map(|part| {
+                let answer = part.answer.to_string();
+                let status = match answers.get(report.day, part.part) {
+                    Some(expected) if expected == answer => Status::Pass,
+                    Some(expected) => Status::Fail {
+                        expected: expected.to_string(),
+                    },
+                    None => Status::Missing,
+                };
+
+                Check {
+                    day: report.day,
+                    part: part.part,
+                    answer,
+                    status,
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::PartReport;
+    use aoc_common::Answer;
+    use std::time::Duration;
+
+    const ANSWERS: &str = r#"
+# Checked by hand.
+[day01]
+part1 = 24000
+part2 = "45000"
+
+[day10]
+part2 = "..##\n.##.\t\"\\"
+"#;
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("24000"));
+        assert_eq!(answers.get(1, Part::Two), Some("45000"));
+        assert_eq!(answers.get(10, Part::One), None);
+        assert_eq!(answers.get(10, Part::Two), Some("..##\n.##.\t\"\\"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn invalid() {
+        let e = Answers::parse("[day01]\npart1 = \"24000\npart3 = 1").unwrap_err();
+        assert_eq!(e.line(), Some(2));
+
+        let e = Answers::parse("[day01]\npart3 = 1").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 1));
+
+        let e = Answers::parse("part1 = 1").unwrap_err();
+        assert_eq!(e.line(), Some(1));
+
+        let e = Answers::parse("[day01]\npart1 = \"a\\q\"").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 11));
+    }
+
+    #[test]
+    fn checks() {
+        let mut answers = Answers::default();
+        answers.set(4, Part::One, "2");
+        answers.set(4, Part::Two, "5");
+
+        let report = |parts: &[(Part, &str)]| Report {
+            day: 4,
+            parse_time: Duration::ZERO,
+            parts: parts
+                .iter()
+                .map(|&(part, answer)| PartReport {
+                    part,
+                    answer: Answer::Text(answer.to_string()),
+                    time: Duration::ZERO,
+                })
+                .collect(),
+        };
+
+        let statuses = verify(&answers, &[report(&[(Part::One, "2"), (Part::Two, "4")])])
+            .into_iter()
+            .map(|check| check.status)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                Status::Pass,
+                Status::Fail {
+                    expected: "5".to_string()
+                }
+            ]
+        );
+
+        let checks = verify(&Answers::default(), &[report(&[(Part::One, "2")])]);
+        assert_eq!(checks[0].status, Status::Missing);
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// How long and how many times to measure a routine.
+#[derive(Clone, Copy, Debug)]
+pub struct Sampling {
+    pub warm_up: Duration,
+    pub measurement: Duration,
+    pub samples: usize,
+}
+
+impl Sampling {
+    pub const DEFAULT: Sampling = Sampling {
+        warm_up: Duration::from_millis(200),
+        measurement: Duration::from_secs(1),
+        samples: 50,
+    };
+
+    pub const QUICK: Sampling = Sampling {
+        warm_up: Duration::from_millis(20),
+        measurement: Duration::from_millis(100),
+        samples: 10,
+    };
+}
+
+/// Statistics over the samples of a routine, in time per iteration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub iterations: u64,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<f64>, iterations: u64) -> Self {
+        samples.sort_by(f64::total_cmp);
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[samples.len() / 2 - 1] + samples[samples.len() / 2]) / 2.0
+        } else {
+            samples[samples.len() / 2]
+        };
+
+        Stats {
+            samples: samples.len(),
+            iterations,
+            mean: Duration::from_secs_f64(mean),
+            median: Duration::from_secs_f64(median),
+            min: Duration::from_secs_f64(samples[0]),
+            max: Duration::from_secs_f64(samples[samples.len() - 1]),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{:.2?} {:.2?} {:.2?}] ± {:.2?}",
+            self.min, self.median, self.max, self.std_dev
+        )
+    }
+}
+
+/// Times `routine`, warming up first to spread the iterations evenly over the samples.
+pub fn measure<T>(sampling: &Sampling, mut routine: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    let mut warm_up_iterations = 0_u64;
+    while warm_up_iterations == 0 || start.elapsed() < sampling.warm_up {
+        black_box(routine());
+        warm_up_iterations += 1;
+    }
+    let per_iteration = start.elapsed().as_secs_f64() / warm_up_iterations as f64;
+
+    let samples = sampling.samples.max(1);
+    let per_sample = sampling.measurement.as_secs_f64() / samples as f64;
+    let iterations = ((per_sample / per_iteration) as u64).max(1);
+
+    let samples = (0..samples)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                black_box(routine());
+            }
+            start.elapsed().as_secs_f64() / iterations as f64
+        })
+        .collect();
+
+    Stats::from_samples(samples, iterations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(vec![4.0, 1.0, 3.0, 2.0], 10);
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.iterations, 10);
+        assert_eq!(stats.mean, Duration::from_millis(2500));
+        assert_eq!(stats.median, Duration::from_millis(2500));
+        assert_eq!(stats.min, Duration::from_secs(1));
+        assert_eq!(stats.max, Duration::from_secs(4));
+    }
+
+    #[test]
+    fn measure_routine() {
+        let sampling = Sampling {
+            warm_up: Duration::ZERO,
+            measurement: Duration::from_millis(5),
+            samples: 5,
+        };
+
+        let mut calls = 0;
+        let stats = measure(&sampling, || calls += 1);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(calls, 1 + 5 * stats.iterations);
+    }
+}
@@ -0,0 +1,416 @@
+use crate::{
+    output::Format,
+    registry::{self, Part},
+};
+use aoc_common::input::InputSource;
+use std::{fmt, path::PathBuf, str::FromStr};
+
+pub const USAGE: &str = "\
+Usage: aoc run <DAYS> [--part <1|2>] [--input <PATH> | --inputs <DIR>] [--format <FORMAT>]
+       aoc verify <DAYS> [OPTIONS] [--answers <PATH>]
+       aoc record <DAYS> [OPTIONS] [--answers <PATH>]
+       aoc generate <DAY> [--size <N>] [--seed <N>]
+       aoc fuzz <DAYS> [--iterations <N>] [--seed <N>]
+
+`verify` checks the answers against the known ones, `record` stores them as the known ones
+after checking them by hand. Known answers are kept in `--answers`, defaulting to answers.toml
+at the root of the workspace.
+
+`generate` prints a random input for DAY, of a typical size unless `--size` is given
+(lines or items, depending on the day), from a random seed unless `--seed` is given.
+
+`fuzz` feeds mutations of the example and generated inputs to the parsers of DAYS,
+10000 per day unless `--iterations` is given, and reports the inputs they panic on.
+
+DAYS is `all`, a day (`7`), a range (`1..5`, `1..=5`) or a comma separated list of those.
+
+Inputs are read from `--input`, a single day's file (`-` for the standard input),
+from `--inputs`, a directory of dayNN.txt files defaulting to $AOC_INPUTS,
+or else from each day's src/input_data/input.txt.
+
+FORMAT is `text` for a table, the default, `json` or `csv`. Both of the latter give one
+record per part with its answer, the type of the answer, and the parse and solve times in
+nanoseconds.";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct UsageError(String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    Run(RunOptions),
+    Verify(RunOptions),
+    Record(RunOptions),
+    Generate(GenerateOptions),
+    Fuzz(FuzzOptions),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    pub days: Vec<u8>,
+    pub parts: Vec<Part>,
+    /// Where to read inputs from, `None` leaving it to the environment.
+    pub source: Option<InputSource>,
+    /// Known answers file, `None` leaving it to the default.
+    pub answers: Option<PathBuf>,
+    pub format: Format,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GenerateOptions {
+    pub day: u8,
+    /// `None` for the typical size of the day's inputs.
+    pub size: Option<usize>,
+    /// `None` for a random seed.
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FuzzOptions {
+    pub days: Vec<u8>,
+    pub iterations: usize,
+    /// `None` for a random seed.
+    pub seed: Option<u64>,
+}
+
+fn parse_number<T: FromStr>(value: &str, what: &str) -> Result<T, UsageError> {
+    value
+        .parse()
+        .map_err(|_| UsageError(format!("invalid {what} `{value}`")))
+}
+
+fn parse_day(s: &str) -> Result<u8, UsageError> {
+    let day = s
+        .trim()
+        .parse::<u8>()
+        .map_err(|_| UsageError(format!("invalid day `{s}`")))?;
+
+    match registry::find(day) {
+        Some(_) => Ok(day),
+        None => Err(UsageError(format!("day {day} is not solved"))),
+    }
+}
+
+pub fn parse_days(s: &str) -> Result<Vec<u8>, UsageError> {
+    if s == "all" {
+        return Ok(registry::DAYS.iter().map(|entry| entry.day).collect());
+    }
+
+    let mut days = vec![];
+    for item in s.split(',') {
+        if let Some((low, high)) = item.split_once("..=") {
+            days.extend(parse_day(low)?..=parse_day(high)?);
+        } else if let Some((low, high)) = item.split_once("..") {
+            // Exclusive upper bound, which does not need to be a solved day itself.
+            let high = high
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| UsageError(format!("invalid day `{high}`")))?;
+            days.extend(parse_day(low)?..high);
+        } else {
+            days.push(parse_day(item)?);
+        }
+    }
+
+    // Unsolved days can only appear inside ranges at this point.
+    if let Some(day) = days.iter().find(|&&day| registry::find(day).is_none()) {
+        return Err(UsageError(format!("day {day} is not solved")));
+    }
+    if days.is_empty() {
+        return Err(UsageError(format!("`{s}` selects no day")));
+    }
+
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+fn parse_format(s: &str) -> Result<Format, UsageError> {
+    match s {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        "csv" => Ok(Format::Csv),
+        _ => Err(UsageError(format!(
+            "invalid format `{s}`, expected text, json or csv"
+        ))),
+    }
+}
+
+fn parse_part(s: &str) -> Result<Part, UsageError> {
+    match s {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(UsageError(format!("invalid part `{s}`, expected 1 or 2"))),
+    }
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, UsageError> {
+    let mut args = args.into_iter();
+
+    let command: fn(RunOptions) -> Command = match args.next().as_deref() {
+        None | Some("help" | "-h" | "--help") => return Ok(Command::Help),
+        Some("run") => Command::Run,
+        Some("verify") => Command::Verify,
+        Some("record") => Command::Record,
+        Some("generate") => return parse_generate(args),
+        Some("fuzz") => return parse_fuzz(args),
+        Some(other) => return Err(UsageError(format!("unknown command `{other}`"))),
+    };
+
+    let mut days = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut source = None;
+    let mut single_day_source = false;
+    let mut answers = None;
+    let mut format = Format::Text;
+
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .ok_or_else(|| UsageError(format!("missing value for `{flag}`")))
+        };
+
+        match arg.as_str() {
+            "-p" | "--part" => parts = vec![parse_part(&value(&arg)?)?],
+            "-i" | "--input" => {
+                source = Some(InputSource::from_arg(&value(&arg)?));
+                single_day_source = true;
+            }
+            "--inputs" => {
+                source = Some(InputSource::Directory(PathBuf::from(value(&arg)?)));
+                single_day_source = false;
+            }
+            "--answers" => answers = Some(PathBuf::from(value(&arg)?)),
+            "-f" | "--format" => format = parse_format(&value(&arg)?)?,
+            "-h" | "--help" => return Ok(Command::Help),
+            flag if flag.starts_with('-') => {
+                return Err(UsageError(format!("unknown option `{flag}`")))
+            }
+            spec if days.is_none() => days = Some(parse_days(spec)?),
+            extra => return Err(UsageError(format!("unexpected argument `{extra}`"))),
+        }
+    }
+
+    let days = days.ok_or_else(|| UsageError("missing days to run".to_string()))?;
+    if single_day_source && days.len() != 1 {
+        return Err(UsageError(
+            "`--input` can only be used when running a single day".to_string(),
+        ));
+    }
+
+    let options = RunOptions {
+        days,
+        parts,
+        source,
+        answers,
+        format,
+    };
+    match command(options) {
+        Command::Run(RunOptions {
+            answers: Some(_), ..
+        }) => Err(UsageError(
+            "`--answers` is only used by `verify` and `record`".to_string(),
+        )),
+        Command::Verify(options) | Command::Record(options) if options.format != Format::Text => {
+            Err(UsageError("`--format` is only used by `run`".to_string()))
+        }
+        command => Ok(command),
+    }
+}
+
+fn parse_generate(mut args: impl Iterator<Item = String>) -> Result<Command, UsageError> {
+    let mut day = None;
+    let mut size = None;
+    let mut seed = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .ok_or_else(|| UsageError(format!("missing value for `{flag}`")))
+        };
+
+        match arg.as_str() {
+            "-s" | "--size" => size = Some(parse_number(&value(&arg)?, "size")?),
+            "--seed" => seed = Some(parse_number(&value(&arg)?, "seed")?),
+            "-h" | "--help" => return Ok(Command::Help),
+            flag if flag.starts_with('-') => {
+                return Err(UsageError(format!("unknown option `{flag}`")))
+            }
+            spec if day.is_none() => day = Some(parse_day(spec)?),
+            extra => return Err(UsageError(format!("unexpected argument `{extra}`"))),
+        }
+    }
+
+    Ok(Command::Generate(GenerateOptions {
+        day: day.ok_or_else(|| UsageError("missing day to generate".to_string()))?,
+        size,
+        seed,
+    }))
+}
+
+fn parse_fuzz(mut args: impl Iterator<Item = String>) -> Result<Command, UsageError> {
+    let mut days = None;
+    let mut iterations = 10_000;
+    let mut seed = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .ok_or_else(|| UsageError(format!("missing value for `{flag}`")))
+        };
+
+        match arg.as_str() {
+            "-n" | "--iterations" => iterations = parse_number(&value(&arg)?, "iteration count")?,
+            "--seed" => seed = Some(parse_number(&value(&arg)?, "seed")?),
+            "-h" | "--help" => return Ok(Command::Help),
+            flag if flag.starts_with('-') => {
+                return Err(UsageError(format!("unknown option `{flag}`")))
+            }
+            spec if days.is_none() => days = Some(parse_days(spec)?),
+            extra => return Err(UsageError(format!("unexpected argument `{extra}`"))),
+        }
+    }
+
+    Ok(Command::Fuzz(FuzzOptions {
+        days: days.ok_or_else(|| UsageError("missing days to fuzz".to_string()))?,
+        iterations,
+        seed,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn days_spec() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+        assert_eq!(parse_days("1..=3"), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_days("1..3"), Ok(vec![1, 2]));
+        assert_eq!(parse_days("5,1..=2,2"), Ok(vec![1, 2, 5]));
+        assert_eq!(parse_days("all").unwrap().len(), registry::DAYS.len());
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("3..3").is_err());
+        assert!(parse_days("9..=12").is_err());
+    }
+
+    #[test]
+    fn run_command() {
+        assert_eq!(
+            parse(args("run 7 --part 2 --input foo.txt")),
+            Ok(Command::Run(RunOptions {
+                days: vec![7],
+                parts: vec![Part::Two],
+                source: Some(InputSource::File(PathBuf::from("foo.txt"))),
+                answers: None,
+                format: Format::Text,
+            }))
+        );
+        assert_eq!(
+            parse(args("run 1..=2 --inputs inputs")),
+            Ok(Command::Run(RunOptions {
+                days: vec![1, 2],
+                parts: Part::ALL.to_vec(),
+                source: Some(InputSource::Directory(PathBuf::from("inputs"))),
+                answers: None,
+                format: Format::Text,
+            }))
+        );
+        assert!(parse(args("run 1..=2 --input -")).is_err());
+        assert!(matches!(
+            parse(args("run 10 --format csv")),
+            Ok(Command::Run(RunOptions {
+                format: Format::Csv,
+                ..
+            }))
+        ));
+        assert!(parse(args("run 10 --format yaml")).is_err());
+        assert_eq!(parse(args("")), Ok(Command::Help));
+        assert!(parse(args("run all --input foo.txt")).is_err());
+        assert!(parse(args("run 1 --part 3")).is_err());
+        assert!(parse(args("run")).is_err());
+    }
+
+    #[test]
+    fn answers_commands() {
+        assert_eq!(
+            parse(args("verify all --part 1 --answers known.toml")),
+            Ok(Command::Verify(RunOptions {
+                days: parse_days("all").unwrap(),
+                parts: vec![Part::One],
+                source: None,
+                answers: Some(PathBuf::from("known.toml")),
+                format: Format::Text,
+            }))
+        );
+        assert_eq!(
+            parse(args("record 3")),
+            Ok(Command::Record(RunOptions {
+                days: vec![3],
+                parts: Part::ALL.to_vec(),
+                source: None,
+                answers: None,
+                format: Format::Text,
+            }))
+        );
+        assert!(parse(args("run 3 --answers known.toml")).is_err());
+        assert!(parse(args("verify 3 --format json")).is_err());
+        assert!(parse(args("verify 3 --answers")).is_err());
+    }
+
+    #[test]
+    fn generate_command() {
+        assert_eq!(
+            parse(args("generate 8 --size 20 --seed 7")),
+            Ok(Command::Generate(GenerateOptions {
+                day: 8,
+                size: Some(20),
+                seed: Some(7),
+            }))
+        );
+        assert_eq!(
+            parse(args("generate 3")),
+            Ok(Command::Generate(GenerateOptions {
+                day: 3,
+                size: None,
+                seed: None,
+            }))
+        );
+        assert!(parse(args("generate")).is_err());
+        assert!(parse(args("generate 1..=3")).is_err());
+        assert!(parse(args("generate 3 --seed x")).is_err());
+    }
+
+    #[test]
+    fn fuzz_command() {
+        assert_eq!(
+            parse(args("fuzz 5,7 --iterations 100 --seed 2")),
+            Ok(Command::Fuzz(FuzzOptions {
+                days: vec![5, 7],
+                iterations: 100,
+                seed: Some(2),
+            }))
+        );
+        assert_eq!(
+            parse(args("fuzz all")),
+            Ok(Command::Fuzz(FuzzOptions {
+                days: parse_days("all").unwrap(),
+                iterations: 10_000,
+                seed: None,
+            }))
+        );
+        assert!(parse(args("fuzz 5 --iterations -1")).is_err());
+    }
+}
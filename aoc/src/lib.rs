@@ -0,0 +1,6 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod output;
+pub mod registry;
+pub mod table;
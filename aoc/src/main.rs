@@ -0,0 +1,185 @@
+use aoc::{
+    answers::{self, Answers, Status},
+    cli::{self, Command, FuzzOptions, GenerateOptions, RunOptions, USAGE},
+    output::{self, Format},
+    registry::{self, Report},
+    table,
+};
+use aoc_common::{
+    input::{self, InputSource},
+    rng::Rng,
+};
+use std::{
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Solves the selected days, reporting their errors along the way. Also tells whether any failed.
+fn solve(options: &RunOptions) -> (Vec<Report>, bool) {
+    let mut reports = vec![];
+    let mut failed = false;
+    let source = options.source.clone().unwrap_or_else(InputSource::from_env);
+
+    for &day in &options.days {
+        let input = match input::load(day, &source) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed = true;
+                continue;
+            }
+        };
+
+        let entry = registry::find(day).expect("days are validated by the command line parser");
+        match entry.run(&input, &options.parts) {
+            Ok(report) => reports.push(report),
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    (reports, failed)
+}
+
+fn exit_code(failed: bool) -> ExitCode {
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run(options: RunOptions) -> ExitCode {
+    let (reports, failed) = solve(&options);
+
+    // Machine-readable formats are printed even when empty, for their consumers to parse.
+    if !reports.is_empty() || options.format != Format::Text {
+        print!("{}", output::render(options.format, &reports));
+    }
+
+    exit_code(failed)
+}
+
+fn verify(options: RunOptions) -> ExitCode {
+    let path = options
+        .answers
+        .clone()
+        .unwrap_or_else(answers::default_path);
+    let known = match Answers::load(&path) {
+        Ok(known) => known,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (reports, mut failed) = solve(&options);
+    let checks = answers::verify(&known, &reports);
+    if checks.is_empty() {
+        return exit_code(failed);
+    }
+
+    let count = |status: fn(&Status) -> bool| checks.iter().filter(|c| status(&c.status)).count();
+    let passed = count(|status| *status == Status::Pass);
+    let wrong = count(|status| matches!(status, Status::Fail { .. }));
+    let missing = count(|status| *status == Status::Missing);
+    failed |= wrong > 0;
+
+    print!("{}", table::render_checks(&checks));
+    println!("\n{passed} passed, {wrong} failed, {missing} missing");
+
+    exit_code(failed)
+}
+
+fn record(options: RunOptions) -> ExitCode {
+    let path = options
+        .answers
+        .clone()
+        .unwrap_or_else(answers::default_path);
+    let mut known = match Answers::load(&path) {
+        Ok(known) => known,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (reports, failed) = solve(&options);
+    if reports.is_empty() {
+        return exit_code(failed);
+    }
+
+    for report in &reports {
+        for part in &report.parts {
+            known.set(report.day, part.part, &part.answer.to_string());
+        }
+    }
+    if let Err(e) = known.save(&path) {
+        eprintln!("error: cannot write `{}`: {e}", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    print!("{}", table::render(&reports));
+    println!("\nAnswers recorded in {}", path.display());
+
+    exit_code(failed)
+}
+
+fn random_seed() -> u64 {
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_nanos() as u64)
+        .unwrap_or_default();
+    // On the error output, to keep the standard one for results.
+    eprintln!("seed: {seed}");
+    seed
+}
+
+fn generate(options: GenerateOptions) -> ExitCode {
+    let entry = registry::find(options.day).expect("days are validated by the command line parser");
+    let seed = options.seed.unwrap_or_else(random_seed);
+
+    println!(
+        "{}",
+        entry.generate(&mut Rng::new(seed), options.size.unwrap_or(entry.size))
+    );
+    ExitCode::SUCCESS
+}
+
+fn fuzz(options: FuzzOptions) -> ExitCode {
+    let mut rng = Rng::new(options.seed.unwrap_or_else(random_seed));
+    let mut failed = false;
+
+    for day in options.days {
+        let entry = registry::find(day).expect("days are validated by the command line parser");
+        let crashes = entry.fuzz(&mut rng, options.iterations);
+        println!("day {day}: {} crashes", crashes.len());
+
+        for crash in crashes {
+            println!("  {crash}");
+            failed = true;
+        }
+    }
+
+    exit_code(failed)
+}
+
+fn main() -> ExitCode {
+    match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Help) => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        Ok(Command::Run(options)) => run(options),
+        Ok(Command::Verify(options)) => verify(options),
+        Ok(Command::Record(options)) => record(options),
+        Ok(Command::Generate(options)) => generate(options),
+        Ok(Command::Fuzz(options)) => fuzz(options),
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    registry::{PartReport, Report},
+    table,
+};
+use aoc_common::Answer;
+use std::fmt::Write;
+
+/// How the answers are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// A table for humans.
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+pub fn render(format: Format, reports: &[Report]) -> String {
+    match format {
+        Format::Text => table::render(reports),
+        Format::Json => json(reports),
+        Format::Csv => csv(reports),
+    }
+}
+
+// Every part solved, with the report of its day.
+fn parts(reports: &[Report]) -> impl Iterator<Item = (&Report, &PartReport)> {
+    reports
+        .iter()
+        .flat_map(|report| report.parts.iter().map(move |part| (report, part)))
+}
+
+fn json_string(s: &str) -> String {
+    let mut output = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => output += "\\\"",
+            '\\' => output += "\\\\",
+            '\n' => output += "\\n",
+            '\t' => output += "\\t",
+            '\r' => output += "\\r",
+            c if c.is_control() => write!(output, "\\u{:04x}", c as u32).unwrap(),
+            c => output.push(c),
+        }
+    }
+    output + "\""
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(n) => n.to_string(),
+        Answer::Text(text) => json_string(text),
+        Answer::Rows(rows) => {
+            let rows = rows.iter().map(|row| json_string(row)).collect::<Vec<_>>();
+            format!("[{}]", rows.join(", "))
+        }
+    }
+}
+
+/// Renders the answers as a JSON array with one object per part, times in nanoseconds.
+pub fn json(reports: &[Report]) -> String {
+    let objects = parts(reports)
+        .map(|(report, part)| {
+            format!(
+                r#"  {{"day": {}, "part": {}, "answer": {}, "type": "{}", "parse_ns": {}, "solve_ns": {}}}"#,
+                report.day,
+                part.part.number(),
+                json_answer(&part.answer),
+                part.answer.kind(),
+                report.parse_time.as_nanos(),
+                part.time.as_nanos()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if objects.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Renders the answers as CSV with one record per part, times in nanoseconds. Rows answers
+/// keep their line breaks, inside quotes.
+pub fn csv(reports: &[Report]) -> String {
+    let mut output = String::from("day,part,answer,type,parse_ns,solve_ns\n");
+    for (report, part) in parts(reports) {
+        writeln!(
+            output,
+            "{},{},{},{},{},{}",
+            report.day,
+            part.part.number(),
+            csv_field(&part.answer.to_string()),
+            part.answer.kind(),
+            report.parse_time.as_nanos(),
+            part.time.as_nanos()
+        )
+        .unwrap();
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Part;
+    use std::time::Duration;
+
+    fn reports() -> Vec<Report> {
+        let part = |part, answer| PartReport {
+            part,
+            answer,
+            time: Duration::from_nanos(40),
+        };
+
+        vec![
+            Report {
+                day: 5,
+                parse_time: Duration::from_nanos(1200),
+                parts: vec![part(Part::One, Answer::Text("C\"M,Z".to_string()))],
+            },
+            Report {
+                day: 10,
+                parse_time: Duration::from_nanos(300),
+                parts: vec![
+                    part(Part::One, Answer::Integer(-13140)),
+                    part(
+                        Part::Two,
+                        Answer::Rows(vec!["##..".to_string(), ".##.".to_string()]),
+                    ),
+                ],
+            },
+        ]
+    }
+
+    #[test]
+    fn render_json() {
+        let expected = r###"[
+  {"day": 5, "part": 1, "answer": "C\"M,Z", "type": "text", "parse_ns": 1200, "solve_ns": 40},
+  {"day": 10, "part": 1, "answer": -13140, "type": "integer", "parse_ns": 300, "solve_ns": 40},
+  {"day": 10, "part": 2, "answer": ["##..", ".##."], "type": "rows", "parse_ns": 300, "solve_ns": 40}
+]
+"###;
+        assert_eq!(json(&reports()), expected);
+        assert_eq!(json(&[]), "[]\n");
+        assert_eq!(json_string("a\u{1}\\"), r#""a\u0001\\""#);
+    }
+
+    #[test]
+    fn render_csv() {
+        let expected = "\
+day,part,answer,type,parse_ns,solve_ns
+5,1,\"C\"\"M,Z\",text,1200,40
+10,1,-13140,integer,300,40
+10,2,\"##..\n.##.\",rows,300,40
+";
+        assert_eq!(csv(&reports()), expected);
+    }
+}
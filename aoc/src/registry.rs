@@ -0,0 +1,204 @@
+use crate::bench::{self, Sampling, Stats};
+use aoc_common::{
+    fuzz::{self, Crash},
+    rng::Rng,
+    Answer, Generate, ParseError, Solution, ToAnswer,
+};
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+/// Timings of each phase of a day.
+#[derive(Debug)]
+pub struct Phases {
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+/// A solver with its types erased, so that every day can be driven the same way.
+pub struct Entry {
+    pub day: u8,
+    /// The example input of the puzzle.
+    pub example: &'static str,
+    /// Size of a typical generated input.
+    pub size: usize,
+    parse: fn(&str) -> Result<(), ParseError>,
+    run: fn(&str, &[Part]) -> Result<Report, ParseError>,
+    bench: fn(&str, &Sampling) -> Result<Phases, ParseError>,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Entry {
+    /// Only parses `input`, discarding the result.
+    pub fn parse(&self, input: &str) -> Result<(), ParseError> {
+        (self.parse)(input)
+    }
+
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+        (self.run)(input, parts)
+    }
+
+    pub fn bench(&self, input: &str, sampling: &Sampling) -> Result<Phases, ParseError> {
+        (self.bench)(input, sampling)
+    }
+
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (self.generate)(rng, size)
+    }
+
+    /// Fuzzes the parser with mutations of the example and of small generated inputs.
+    pub fn fuzz(&self, rng: &mut Rng, iterations: usize) -> Vec<Crash> {
+        let mut corpus = vec![self.example.to_string()];
+        corpus.extend((1..=10).map(|size| self.generate(rng, size)));
+
+        fuzz::fuzz(rng, &corpus, iterations, |input| {
+            let _ = self.parse(input);
+        })
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(input).map(drop).map_err(|e| e.in_day(S::DAY))
+}
+
+fn execute<S: Solution>(input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|e| e.in_day(S::DAY))?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part_one(&parsed).to_answer(),
+                Part::Two => S::part_two(&parsed).to_answer(),
+            };
+
+            PartReport {
+                part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        day: S::DAY,
+        parse_time,
+        parts,
+    })
+}
+
+fn measure<S: Solution>(input: &str, sampling: &Sampling) -> Result<Phases, ParseError> {
+    let parsed = S::parse(input).map_err(|e| e.in_day(S::DAY))?;
+
+    Ok(Phases {
+        parse: bench::measure(sampling, || S::parse(input)),
+        part_one: bench::measure(sampling, || S::part_one(&parsed)),
+        part_two: bench::measure(sampling, || S::part_two(&parsed)),
+    })
+}
+
+const fn entry<S: Generate>(example: &'static str) -> Entry {
+    Entry {
+        day: S::DAY,
+        example,
+        size: S::SIZE,
+        parse: parse::<S>,
+        run: execute::<S>,
+        bench: measure::<S>,
+        generate: S::generate,
+    }
+}
+
+pub static DAYS: &[Entry] = &[
+    entry::<day01::Day01>(include_str!("../../day01/src/input_data/test.txt")),
+    entry::<day02::Day02>(include_str!("../../day02/src/input_data/test.txt")),
+    entry::<day03::Day03>(include_str!("../../day03/src/input_data/test.txt")),
+    entry::<day04::Day04>(include_str!("../../day04/src/input_data/test.txt")),
+    entry::<day05::Day05>(include_str!("../../day05/src/input_data/test.txt")),
+    entry::<day06::Day06>(include_str!("../../day06/src/input_data/test.txt")),
+    entry::<day07::Day07>(include_str!("../../day07/src/input_data/test.txt")),
+    entry::<day08::Day08>(include_str!("../../day08/src/input_data/test.txt")),
+    entry::<day09::Day09>(include_str!("../../day09/src/input_data/test_a.txt")),
+    entry::<day10::Day10>(include_str!("../../day10/src/input_data/test.txt")),
+];
+
+pub fn find(day: u8) -> Option<&'static Entry> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let days = DAYS.iter().map(|entry| entry.day).collect::<Vec<_>>();
+        assert_eq!(days, (1..=10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn run_selected_part() {
+        let report = find(1)
+            .unwrap()
+            .run(find(1).unwrap().example, &[Part::Two])
+            .unwrap();
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].part, Part::Two);
+        assert_eq!(report.parts[0].answer, Answer::Integer(45000));
+    }
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for entry in DAYS {
+            let input = entry.generate(&mut Rng::new(1), entry.size);
+            entry.run(&input, &Part::ALL).unwrap();
+        }
+    }
+
+    #[test]
+    fn parsers_survive_fuzzing() {
+        for entry in DAYS {
+            let crashes = entry.fuzz(&mut Rng::new(0), 200);
+            assert!(crashes.is_empty(), "day {}: {}", entry.day, crashes[0]);
+        }
+    }
+
+    #[test]
+    fn parse_error_names_the_day() {
+        let e = find(10).unwrap().run("noop\naddx", &Part::ALL).unwrap_err();
+        assert_eq!((e.day(), e.line()), (Some(10), Some(2)));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    answers::{Check, Status},
+    registry::Report,
+};
+
+/// Lays out `rows` under `headers`, multi-line cells spanning several rows. Columns listed in
+/// `left` are left aligned, the others right aligned.
+fn layout<const N: usize>(headers: [&str; N], rows: &[[String; N]], left: &[usize]) -> String {
+    let mut lines: Vec<[String; N]> = vec![];
+    for row in rows {
+        let height = row
+            .iter()
+            .map(|cell| cell.lines().count())
+            .max()
+            .unwrap_or(0);
+        for i in 0..height.max(1) {
+            lines.push(
+                row.each_ref()
+                    .map(|cell| cell.lines().nth(i).unwrap_or_default().to_string()),
+            );
+        }
+    }
+
+    let mut widths = headers.map(|header| header.chars().count());
+    for line in &lines {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut output = String::new();
+    let mut push_line = |cells: &[String; N]| {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                if left.contains(&i) {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        output += line.trim_end();
+        output += "\n";
+    };
+
+    push_line(&headers.map(|header| header.to_string()));
+    push_line(&widths.map(|width| "-".repeat(width)));
+    for line in &lines {
+        push_line(line);
+    }
+
+    output
+}
+
+/// Renders the answers as a text table, multi-line answers spanning several rows.
+pub fn render(reports: &[Report]) -> String {
+    let mut rows = vec![];
+
+    for report in reports {
+        for (i, part) in report.parts.iter().enumerate() {
+            let first = |cell: String| if i == 0 { cell } else { String::new() };
+
+            rows.push([
+                first(report.day.to_string()),
+                part.part.number().to_string(),
+                part.answer.to_string(),
+                first(format!("{:.2?}", report.parse_time)),
+                format!("{:.2?}", part.time),
+            ]);
+        }
+    }
+
+    // Answers read better left aligned, numbers and timings right aligned.
+    layout(["Day", "Part", "Answer", "Parse", "Solve"], &rows, &[2])
+}
+
+/// Renders the outcome of checking answers, with the expected answer of failed checks.
+pub fn render_checks(checks: &[Check]) -> String {
+    let rows = checks
+        .iter()
+        .map(|check| {
+            let (status, expected) = match &check.status {
+                Status::Pass => ("pass", ""),
+                Status::Fail { expected } => ("FAIL", expected.as_str()),
+                Status::Missing => ("missing", ""),
+            };
+
+            [
+                check.day.to_string(),
+                check.part.number().to_string(),
+                status.to_string(),
+                check.answer.clone(),
+                expected.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    layout(
+        ["Day", "Part", "Status", "Answer", "Expected"],
+        &rows,
+        &[2, 3, 4],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        answers::{Check, Status},
+        registry::{Part, PartReport},
+    };
+    use aoc_common::Answer;
+    use std::time::Duration;
+
+    #[test]
+    fn render_multiline_answer() {
+        let reports = [Report {
+            day: 10,
+            parse_time: Duration::from_micros(3),
+            parts: vec![
+                PartReport {
+                    part: Part::One,
+                    answer: Answer::Integer(13140),
+                    time: Duration::from_micros(12),
+                },
+                PartReport {
+                    part: Part::Two,
+                    answer: Answer::Rows(vec!["##..".to_string(), ".##.".to_string()]),
+                    time: Duration::from_micros(5),
+                },
+            ],
+        }];
+
+        let expected = "\
+Day  Part  Answer   Parse    Solve
+---  ----  ------  ------  -------
+ 10     1  13140   3.00µs  12.00µs
+        2  ##..             5.00µs
+           .##.
+";
+        assert_eq!(render(&reports), expected);
+    }
+
+    #[test]
+    fn render_failed_check() {
+        let checks = [
+            Check {
+                day: 1,
+                part: Part::One,
+                answer: "24000".to_string(),
+                status: Status::Pass,
+            },
+            Check {
+                day: 1,
+                part: Part::Two,
+                answer: "45001".to_string(),
+                status: Status::Fail {
+                    expected: "45000".to_string(),
+                },
+            },
+            Check {
+                day: 2,
+                part: Part::One,
+                answer: "15".to_string(),
+                status: Status::Missing,
+            },
+        ];
+
+        let expected = "\
+Day  Part  Status   Answer  Expected
+---  ----  -------  ------  --------
+  1     1  pass     24000
+  1     2  FAIL     45001   45000
+  2     1  missing  15
+";
+        assert_eq!(render_checks(&checks), expected);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::Day01;
+use aoc_common::{rng::Rng, Generate};
+
+impl Generate for Day01 {
+    const SIZE: usize = 250;
+
+    // `size` elves carrying a handful of snacks each.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                (0..rng.range(1..=15))
+                    .map(|_| rng.range(1000..=60000_u32).to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
@@ -0,0 +1,408 @@
+mod generator;
+pub mod report;
+
+use aoc_common::{stream, stream::StreamError, ParseError, Solution, Stream};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+    fmt::{self, Display},
+    io::BufRead,
+};
+
+pub struct Day01;
+
+/// The food items an elf carries, by their calories.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the elf in the input, from 0.
+    pub index: usize,
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    /// Calories of every item together, which parsing checked fit in a `u64`.
+    pub fn total(&self) -> u64 {
+        self.items.iter().sum()
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Calories of the richest item, 0 without items.
+    pub fn max_item(&self) -> u64 {
+        self.items.iter().copied().max().unwrap_or(0)
+    }
+}
+
+/// Elves carrying more than `threshold` calories in total.
+pub fn exceeding(elves: &[Elf], threshold: u64) -> impl Iterator<Item = &Elf> {
+    elves.iter().filter(move |elf| elf.total() > threshold)
+}
+
+/// Number of elves by total, in buckets of `width` calories: the start of every bucket holding
+/// an elf, lowest first.
+pub fn histogram(elves: &[Elf], width: u64) -> Vec<(u64, usize)> {
+    assert!(width > 0, "empty histogram bucket");
+
+    // Only the buckets in use, however far apart the totals are.
+    let mut counts = BTreeMap::new();
+    for elf in elves {
+        *counts.entry(elf.total() / width).or_insert(0) += 1;
+    }
+    counts
+        .into_iter()
+        .map(|(bucket, count)| (bucket * width, count))
+        .collect()
+}
+
+// The `n` highest totals pushed so far, in a min-heap to drop the lowest one in O(log n). Ties
+// keep the first elf.
+struct Podium {
+    n: usize,
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl Podium {
+    fn new(n: usize) -> Self {
+        Self {
+            n,
+            heap: BinaryHeap::with_capacity(n + 1),
+        }
+    }
+
+    fn push(&mut self, index: usize, total: u64) {
+        self.heap.push(Reverse((total, Reverse(index))));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+
+    // Elves and their totals, highest first.
+    fn into_elves(self) -> Vec<(usize, u64)> {
+        // Sorted by `Reverse`, so highest first.
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(index)))| (index, total))
+            .collect()
+    }
+}
+
+/// The elves carrying the most calories, with statistics over every elf.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TopN {
+    /// Index of each elf and its total, highest first.
+    pub elves: Vec<(usize, u64)>,
+    /// Mean total of every elf, 0 without elves.
+    pub mean: f64,
+    /// Median total of every elf, 0 without elves.
+    pub median: f64,
+}
+
+impl TopN {
+    /// Calories carried by the top elves together.
+    pub fn total(&self) -> u64 {
+        self.elves.iter().map(|&(_, total)| total).sum()
+    }
+}
+
+/// One line per elf, numbered from 1, then the statistics.
+impl Display for TopN {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, total) in &self.elves {
+            writeln!(f, "Elf {}: {total}", index + 1)?;
+        }
+        writeln!(f, "Total: {}", self.total())?;
+        write!(f, "Mean: {:.1}, median: {:.1}", self.mean, self.median)
+    }
+}
+
+/// The `n` elves carrying the most calories.
+pub fn top_n(elves: &[Elf], n: usize) -> TopN {
+    let mut podium = Podium::new(n);
+    let mut totals = Vec::with_capacity(elves.len());
+    for elf in elves {
+        podium.push(elf.index, elf.total());
+        totals.push(elf.total());
+    }
+
+    let (mean, median) = match totals.len() {
+        0 => (0.0, 0.0),
+        len => {
+            let sum = totals.iter().map(|&total| u128::from(total)).sum::<u128>();
+            let (lower, &mut middle, _) = totals.select_nth_unstable(len / 2);
+            let median = match len % 2 {
+                1 => middle as f64,
+                // The other middle total is the highest of the lower half.
+                _ => (middle as f64 + *lower.iter().max().unwrap() as f64) / 2.0,
+            };
+            (sum as f64 / len as f64, median)
+        }
+    };
+
+    TopN {
+        elves: podium.into_elves(),
+        mean,
+        median,
+    }
+}
+
+// Calories carried by the `n` elves carrying the most together, without the statistics of
+// `top_n`.
+fn highest(elves: &[Elf], n: usize) -> u64 {
+    let mut podium = Podium::new(n);
+    for elf in elves {
+        podium.push(elf.index, elf.total());
+    }
+    podium.into_elves().iter().map(|&(_, total)| total).sum()
+}
+
+// Gathers the items of the elf being read, line by line.
+#[derive(Default)]
+struct Tally {
+    elves: usize,
+    current: Option<Elf>,
+    total: u64,
+}
+
+impl Tally {
+    // Reads a line, returning the elf it ends if it is blank.
+    fn line(&mut self, line: &str) -> Result<Option<Elf>, ParseError> {
+        // Blank lines separate the elves.
+        if line.trim().is_empty() {
+            return Ok(self.finish());
+        }
+
+        for item in line.split_whitespace() {
+            let calories = item
+                .parse::<u64>()
+                .map_err(|e| ParseError::at(line, item, format!("invalid calorie count: {e}")))?;
+            self.total = self
+                .total
+                .checked_add(calories)
+                .ok_or_else(|| ParseError::at(line, item, "calorie total overflows"))?;
+
+            let index = self.elves;
+            self.current
+                .get_or_insert_with(|| Elf {
+                    index,
+                    items: vec![],
+                })
+                .items
+                .push(calories);
+        }
+        Ok(None)
+    }
+
+    // Ends the elf being read, if any.
+    fn finish(&mut self) -> Option<Elf> {
+        let elf = self.current.take()?;
+        self.elves += 1;
+        self.total = 0;
+        Some(elf)
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<Elf>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut tally = Tally::default();
+        let mut elves = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            elves.extend(tally.line(line).map_err(|e| e.at_line(i + 1))?);
+        }
+        elves.extend(tally.finish());
+
+        Ok(elves)
+    }
+
+    fn part_one(elves: &Self::Input<'_>) -> Self::PartOne {
+        highest(elves, 1)
+    }
+
+    fn part_two(elves: &Self::Input<'_>) -> Self::PartTwo {
+        highest(elves, 3)
+    }
+}
+
+// Only keeps the three highest totals, on a podium, and the items of one elf at a time.
+impl Stream for Day01 {
+    fn solve_reader(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), StreamError> {
+        let mut tally = Tally::default();
+        let mut podium = Podium::new(3);
+
+        stream::lines(reader, |line| {
+            if let Some(elf) = tally.line(line)? {
+                podium.push(elf.index, elf.total());
+            }
+            Ok(())
+        })?;
+        if let Some(elf) = tally.finish() {
+            podium.push(elf.index, elf.total());
+        }
+
+        let top = podium.into_elves();
+        let total = |n| top.iter().take(n).map(|&(_, total)| total).sum();
+        Ok((total(1), total(3)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{check::check, rng::Rng, Generate};
+    use std::io::BufReader;
+
+    #[test]
+    fn day01_a() {
+        let input = Day01::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day01::part_one(&input), 24000);
+    }
+
+    #[test]
+    fn day01_b() {
+        let input = Day01::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day01::part_two(&input), 45000);
+    }
+
+    #[test]
+    fn day01_invalid() {
+        let e = Day01::parse("1000\n\n2000\n20x0").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (Some(4), 1, "20x0"));
+    }
+
+    #[test]
+    fn day01_generated() {
+        // Up to 15 snacks per elf, of 1000 to 60000 calories each.
+        for seed in 0..10 {
+            let input = Day01::generate(&mut Rng::new(seed), Day01::SIZE);
+            let elves = Day01::parse(&input).unwrap();
+            assert_eq!(elves.len(), Day01::SIZE);
+            assert!(elves.iter().all(|elf| (1..=15).contains(&elf.item_count())
+                && elf.items.iter().all(|c| (1000..=60000).contains(c))));
+        }
+    }
+
+    #[test]
+    fn day01_stream() {
+        // Blank lines holding spaces or ending in `\r` still separate the elves, and a buffer of
+        // 2 bytes splits the calorie counts across reads.
+        let input = "1000\r\n2000\r\n \t\r\n3000\n\n4000 500\n7000";
+        let elves = Day01::parse(input).unwrap();
+        assert_eq!(
+            Day01::solve_reader(BufReader::with_capacity(2, input.as_bytes())).unwrap(),
+            (11500, 11500 + 3000 + 3000)
+        );
+        assert_eq!(
+            (Day01::part_one(&elves), Day01::part_two(&elves)),
+            (11500, 17500)
+        );
+        assert_eq!(Day01::solve_reader("".as_bytes()).unwrap(), (0, 0));
+
+        let e = Day01::solve_reader("1000\n\n2000\n20x0".as_bytes()).unwrap_err();
+        assert!(matches!(e, StreamError::Parse(e) if e.line() == Some(4)));
+    }
+
+    // Elves carrying a single item each.
+    fn carrying(totals: &[u64]) -> Vec<Elf> {
+        (0..totals.len())
+            .map(|index| Elf {
+                index,
+                items: vec![totals[index]],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn day01_elves() {
+        let elves = Day01::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(elves.len(), 5);
+        assert_eq!(
+            elves[1],
+            Elf {
+                index: 1,
+                items: vec![4000]
+            }
+        );
+        assert_eq!((elves[2].item_count(), elves[2].max_item()), (2, 6000));
+        assert_eq!(elves.iter().map(Elf::total).max(), Some(24000));
+
+        let rich = exceeding(&elves, 10000).map(|elf| elf.index);
+        assert_eq!(rich.collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(
+            histogram(&elves, 5000),
+            [(0, 1), (5000, 1), (10000, 2), (20000, 1)]
+        );
+        assert_eq!(histogram(&[], 5000), []);
+
+        // Totals far apart only make the buckets they fall in.
+        let spread = carrying(&[3, u64::MAX, 7, u64::MAX / 2]);
+        assert_eq!(
+            histogram(&spread, 5),
+            [
+                (0, 1),
+                (5, 1),
+                (u64::MAX / 2 / 5 * 5, 1),
+                (u64::MAX / 5 * 5, 1)
+            ]
+        );
+
+        // Beyond what an `i32` holds.
+        let elves = Day01::parse("3000000000\n3000000000\n\n1").unwrap();
+        assert_eq!(Day01::part_two(&elves), 6000000001);
+        assert!(Day01::parse("18446744073709551615\n1").is_err());
+        assert!(Day01::parse("-5").is_err());
+    }
+
+    #[test]
+    fn day01_top_n() {
+        let elves = Day01::parse(include_str!("input_data/test.txt")).unwrap();
+        let top = top_n(&elves, 3);
+        assert_eq!(top.elves, [(3, 24000), (2, 11000), (4, 10000)]);
+        assert_eq!(
+            (top.total(), top.mean, top.median),
+            (45000, 11000.0, 10000.0)
+        );
+        assert_eq!(top_n(&elves, 0).elves, []);
+        assert_eq!(top_n(&elves, 9).elves.len(), 5);
+
+        // Ties keep the first elf, an even count averages the middle totals.
+        let top = top_n(&carrying(&[5, 1, 5, 3]), 1);
+        assert_eq!((top.elves, top.median), (vec![(0, 5)], 4.0));
+        assert_eq!(
+            top_n(&[], 2),
+            TopN {
+                elves: vec![],
+                mean: 0.0,
+                median: 0.0
+            }
+        );
+
+        // Against sorting every elf.
+        check::<Day01>(1..=200, |elves| {
+            let mut sorted = elves
+                .iter()
+                .map(|elf| (elf.index, elf.total()))
+                .collect::<Vec<_>>();
+            sorted.sort_by_key(|&(index, total)| (Reverse(total), index));
+
+            [0, 1, 3, elves.len() / 2, elves.len() + 1]
+                .into_iter()
+                .all(|n| top_n(elves, n).elves == sorted[..n.min(elves.len())])
+        });
+    }
+
+    #[test]
+    fn day01_top_three() {
+        check::<Day01>(1..=500, |elves| {
+            Day01::part_two(elves) >= Day01::part_one(elves)
+        });
+    }
+}
@@ -1,39 +1,99 @@
-fn solve_for_n<const N: usize>(input: &str) -> i32 {
-    let mut sums = input
-        .split("\n\n")
-        .map(|a| {
-            a.split_whitespace()
-                .map(|b| b.parse::<i32>().unwrap())
-                .sum::<i32>()
-        })
-        .collect::<Vec<i32>>();
-
-    sums.sort();
-    sums.reverse();
-
-    sums.iter().take(N).sum()
+use aoc_common::{
+    input::{self, InputSource},
+    Solution,
+};
+use day01::{
+    exceeding,
+    report::{self, Format},
+    top_n, Day01,
+};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: day01 [INPUT] [--top <N> | --report <table|csv> [--above <CALORIES>]]
+
+Without options, prints the answers of both parts. `--top` prints the N elves carrying the
+most calories, with the mean and median totals of every elf. `--report` prints the item count,
+total and richest item of every elf, or of those carrying more than `--above` calories.
+Elves are numbered from 1 in input order.";
+
+// What to print about the elves.
+enum Query {
+    Answers,
+    Top(usize),
+    Report { format: Format, above: Option<u64> },
 }
 
-fn main() {
-    let input = include_str!("input_data/input.txt");
+fn parse_args() -> Result<Option<(Option<InputSource>, Query)>, String> {
+    let mut args = std::env::args().skip(1);
+    let mut source = None;
+    let (mut top, mut format, mut above) = (None, None, None);
 
-    println!("Part 1: {}", solve_for_n::<1>(input));
-    println!("Part 2: {}", solve_for_n::<3>(input));
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--top" => match args.next().map(|n| n.parse::<usize>()) {
+                Some(Ok(n)) if n > 0 => top = Some(n),
+                _ => return Err("`--top` takes a positive number".to_string()),
+            },
+            "--report" => match args.next().as_deref() {
+                Some("table") => format = Some(Format::Table),
+                Some("csv") => format = Some(Format::Csv),
+                _ => return Err("`--report` takes `table` or `csv`".to_string()),
+            },
+            "--above" => match args.next().map(|n| n.parse::<u64>()) {
+                Some(Ok(calories)) => above = Some(calories),
+                _ => return Err("`--above` takes a number of calories".to_string()),
+            },
+            _ if source.is_none() => source = Some(InputSource::from_arg(&arg)),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    let query = match (top, format, above) {
+        (Some(_), Some(_), _) => return Err("`--top` and `--report` cannot be combined".into()),
+        (_, None, Some(_)) => return Err("`--above` only applies to `--report`".into()),
+        (Some(n), None, None) => Query::Top(n),
+        (None, Some(format), above) => Query::Report { format, above },
+        (None, None, None) => Query::Answers,
+    };
+    Ok(Some((source, query)))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn main() -> ExitCode {
+    let (source, query) = match parse_args() {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => return aoc_common::exit(Err(format!("{e}\n\n{USAGE}"))),
+    };
 
-    #[test]
-    fn day01_a() {
-        let input = include_str!("input_data/test.txt");
-        assert_eq!(solve_for_n::<1>(input.into()), 24000);
+    let source = source.unwrap_or_else(InputSource::from_env);
+    // Both answers only need the highest totals, read as the input streams in.
+    if let Query::Answers = query {
+        return aoc_common::main_stream_from::<Day01>(&source);
     }
 
-    #[test]
-    fn day01_b() {
-        let input = include_str!("input_data/test.txt");
-        assert_eq!(solve_for_n::<3>(input.into()), 45000);
+    let input = match input::load(Day01::DAY, &source) {
+        Ok(input) => input,
+        Err(e) => return aoc_common::exit(Err(e)),
+    };
+    let elves = match Day01::parse(&input) {
+        Ok(elves) => elves,
+        Err(e) => return aoc_common::exit(Err(e.in_day(Day01::DAY))),
+    };
+
+    match query {
+        Query::Top(n) => println!("{}", top_n(&elves, n)),
+        Query::Report { format, above } => {
+            let elves = match above {
+                Some(threshold) => exceeding(&elves, threshold).collect::<Vec<_>>(),
+                None => elves.iter().collect(),
+            };
+            print!("{}", report::render(elves, format));
+        }
+        Query::Answers => unreachable!("answered from the stream"),
     }
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,95 @@
+use crate::Elf;
+use std::fmt::Write;
+
+/// How the report on the elves is printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Csv,
+}
+
+const HEADER: [&str; 4] = ["elf", "items", "total", "max item"];
+
+// Elves are numbered from 1, as in the puzzle.
+fn fields(elf: &Elf) -> [String; 4] {
+    [
+        (elf.index + 1).to_string(),
+        elf.item_count().to_string(),
+        elf.total().to_string(),
+        elf.max_item().to_string(),
+    ]
+}
+
+/// One row per elf with its number, item count, total and richest item.
+pub fn render<'a>(elves: impl IntoIterator<Item = &'a Elf>, format: Format) -> String {
+    let rows = elves.into_iter().map(fields).collect::<Vec<_>>();
+    match format {
+        Format::Table => table(&rows),
+        Format::Csv => csv(&rows),
+    }
+}
+
+// Numbers right-aligned under their headers.
+fn table(rows: &[[String; 4]]) -> String {
+    let mut widths = HEADER.map(str::len);
+    for row in rows {
+        for (width, field) in widths.iter_mut().zip(row) {
+            *width = (*width).max(field.len());
+        }
+    }
+
+    let mut output = String::new();
+    let header = HEADER.map(String::from);
+    for row in std::iter::once(&header).chain(rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(field, width)| format!("{field:>width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(output, "{line}").unwrap();
+    }
+    output
+}
+
+fn csv(rows: &[[String; 4]]) -> String {
+    let mut output = String::from("elf,items,total,max_item\n");
+    for row in rows {
+        writeln!(output, "{}", row.join(",")).unwrap();
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports() {
+        let elves = [
+            Elf {
+                index: 0,
+                items: vec![1000, 2000],
+            },
+            Elf {
+                index: 1,
+                items: vec![24000],
+            },
+        ];
+
+        let expected = "\
+elf  items  total  max item
+  1      2   3000      2000
+  2      1  24000     24000
+";
+        assert_eq!(render(&elves, Format::Table), expected);
+
+        let expected = "\
+elf,items,total,max_item
+1,2,3000,2000
+2,1,24000,24000
+";
+        assert_eq!(render(&elves, Format::Csv), expected);
+        assert_eq!(render(&[], Format::Csv), "elf,items,total,max_item\n");
+    }
+}
@@ -0,0 +1,177 @@
+//! Every reading of the second column of a strategy guide, as moves or as outcomes, scored.
+
+use crate::{compute_scores, config::Config, rules::Outcome, Move, Round};
+use aoc_common::ParseError;
+use std::{
+    cmp::Reverse,
+    fmt::{self, Display},
+};
+
+// The orders of three letters.
+const PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+/// What the three letters of the second column stand for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reading {
+    Moves([Move; 3]),
+    Outcomes([Outcome; 3]),
+}
+
+/// A reading of a guide, with the moves played and their scores round by round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mapping {
+    pub letters: [char; 3],
+    pub reading: Reading,
+    /// Moves of the opponent and the player in every round.
+    pub rounds: Vec<(Move, Move)>,
+    pub scores: Vec<i32>,
+}
+
+impl Mapping {
+    pub fn total(&self) -> i32 {
+        self.scores.iter().sum()
+    }
+}
+
+/// Its letters and total, then with `{:#}` the score of every round.
+impl Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, letter) in self.letters.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            match self.reading {
+                Reading::Moves(moves) => write!(f, "{separator}{letter} = {}", moves[i])?,
+                Reading::Outcomes(outcomes) => write!(f, "{separator}{letter} = {}", outcomes[i])?,
+            }
+        }
+        write!(f, ": {}", self.total())?;
+
+        if f.alternate() {
+            for (i, ((opponent, own), score)) in self.rounds.iter().zip(&self.scores).enumerate() {
+                write!(f, "\n  {}. {own} against {opponent}: {score}", i + 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Every reading of a guide, highest total first, ties in the order they were tried.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Analysis {
+    pub moves: Vec<Mapping>,
+    pub outcomes: Vec<Mapping>,
+}
+
+/// The totals of every reading, then the best and the worst of each kind round by round.
+impl Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (kind, mappings) in [("moves", &self.moves), ("outcomes", &self.outcomes)] {
+            writeln!(f, "Second column as {kind}:")?;
+            for mapping in mappings {
+                writeln!(f, "  {mapping}")?;
+            }
+            if let (Some(best), Some(worst)) = (mappings.first(), mappings.last()) {
+                writeln!(f, "Best: {best:#}")?;
+                writeln!(f, "Worst: {worst:#}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Scores `rounds` read with every assignment of the player's letters of `config` to moves,
+/// then to outcomes. The second column of every round has to be one of these letters.
+pub fn analyze(config: &Config, rounds: &[Round]) -> Result<Analysis, ParseError> {
+    let letters = [0, 1, 2].map(|i| config.rules().moves()[i].player);
+    // The letter of each round, by the move it stands for.
+    let guide = config
+        .as_moves(rounds)?
+        .into_iter()
+        .map(|(opponent, own)| (opponent, own.index()))
+        .collect::<Vec<_>>();
+
+    let mapping = |reading| {
+        let rounds = guide
+            .iter()
+            .map(|&(opponent, letter)| match reading {
+                Reading::Moves(moves) => (opponent, moves[letter]),
+                Reading::Outcomes(outcomes) => (opponent, opponent.for_outcome(outcomes[letter])),
+            })
+            .collect::<Vec<_>>();
+        Mapping {
+            letters,
+            reading,
+            scores: compute_scores(config, &rounds),
+            rounds,
+        }
+    };
+    let sorted = |readings: [Reading; 6]| {
+        let mut mappings = readings.map(mapping).to_vec();
+        mappings.sort_by_key(|mapping| Reverse(mapping.total()));
+        mappings
+    };
+
+    let outcomes = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+    Ok(Analysis {
+        moves: sorted(PERMUTATIONS.map(|p| Reading::Moves(p.map(|i| Move::ALL[i])))),
+        outcomes: sorted(PERMUTATIONS.map(|p| Reading::Outcomes(p.map(|i| outcomes[i])))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::Solution;
+
+    #[test]
+    fn analysis() {
+        let rounds = Day02::parse(include_str!("input_data/test.txt")).unwrap();
+        let analysis = analyze(&Config::default(), &rounds).unwrap();
+
+        let totals = |mappings: &[Mapping]| mappings.iter().map(Mapping::total).collect::<Vec<_>>();
+        assert_eq!(totals(&analysis.moves), [24, 15, 15, 15, 15, 6]);
+        assert_eq!(totals(&analysis.outcomes), [18, 15, 15, 15, 15, 12]);
+
+        let best = &analysis.moves[0];
+        assert_eq!(
+            best.reading,
+            Reading::Moves([Move::Scissors, Move::Paper, Move::Rock])
+        );
+        assert_eq!(best.scores, [8, 9, 7]);
+        assert_eq!(best.to_string(), "X = scissors, Y = paper, Z = rock: 24");
+        assert_eq!(
+            format!("{:#}", analysis.outcomes[5]),
+            "X = lose, Y = draw, Z = win: 12\n  \
+             1. rock against rock: 4\n  \
+             2. rock against paper: 1\n  \
+             3. rock against scissors: 7"
+        );
+
+        // The puzzle's readings are among them.
+        let reading = |mappings: &[Mapping], reading| {
+            mappings
+                .iter()
+                .find(|m| m.reading == reading)
+                .unwrap()
+                .total()
+        };
+        assert_eq!(
+            reading(&analysis.moves, Reading::Moves(Move::ALL)),
+            Day02::part_one(&rounds)
+        );
+        assert_eq!(
+            reading(
+                &analysis.outcomes,
+                Reading::Outcomes([Outcome::Lose, Outcome::Draw, Outcome::Win])
+            ),
+            Day02::part_two(&rounds)
+        );
+    }
+}
@@ -0,0 +1,399 @@
+//! How to read and score a strategy guide, loaded from `key = value` lines:
+//!
+//! ```text
+//! # Scores of the moves, and of the outcomes on top of them.
+//! score.rock = 1
+//! score.lose = 0
+//! # Letters of the opponent's moves, and of the second column read as moves or outcomes.
+//! opponent.rock = A
+//! player.rock = X
+//! outcome.lose = X
+//! ```
+//!
+//! Keys left out keep the values of the puzzle, blank lines and lines starting with `#` are
+//! skipped.
+
+use crate::{compute_scores, rules::Outcome, rules::Rules, Move, Round};
+use aoc_common::{
+    parse::{finish, key_value, signed},
+    ParseError,
+};
+
+// Names of the outcomes in keys, in the order of their letters.
+const OUTCOMES: [(&str, Outcome); 3] = [
+    ("lose", Outcome::Lose),
+    ("draw", Outcome::Draw),
+    ("win", Outcome::Win),
+];
+
+/// Scores and letters of the moves and outcomes of a strategy guide.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    rules: Rules,
+    // Letter of each outcome in the second column, in the order of `OUTCOMES`.
+    outcomes: [char; 3],
+}
+
+// A letter of the configuration, with the line and value setting it unless left by default.
+#[derive(Clone, Copy)]
+struct Letter<'a> {
+    letter: char,
+    set: Option<(usize, &'a str, &'a str)>,
+}
+
+impl<'a> Letter<'a> {
+    fn default(letter: char) -> Self {
+        Self { letter, set: None }
+    }
+}
+
+// Checks that no two moves or outcomes share a letter of a column, pointing at the latest
+// line setting one of them.
+fn check_letters(column: &str, letters: &[Letter]) -> Result<(), ParseError> {
+    for (i, a) in letters.iter().enumerate() {
+        for b in &letters[..i] {
+            if a.letter.eq_ignore_ascii_case(&b.letter) {
+                // The letters of the puzzle differ, so one of them was set.
+                let (line_number, line, value) = a.set.max(b.set).unwrap();
+                let reason = format!("letter `{value}` given twice in the {column} column");
+                return Err(ParseError::at(line, value, reason).at_line(line_number));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Letters separated by commas, for errors.
+fn listed(letters: impl Iterator<Item = char>) -> String {
+    letters.map(String::from).collect::<Vec<_>>().join(", ")
+}
+
+// Error about `field` of `line` not being one of `letters`, only listed once rejected.
+fn unknown(line: &str, field: &str, what: &str, letters: String) -> ParseError {
+    ParseError::at(
+        line,
+        field,
+        format!("unknown {what}, expected one of {letters}"),
+    )
+}
+
+impl Default for Config {
+    /// The puzzle's reading of the guide: A, B and C or X, Y and Z for rock, paper and
+    /// scissors, or X, Y and Z to lose, draw and win.
+    fn default() -> Self {
+        Self {
+            rules: Rules::classic(),
+            outcomes: ['X', 'Y', 'Z'],
+        }
+    }
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let puzzle = Self::default();
+        let mut moves = puzzle.rules.moves().to_vec();
+        let mut outcome_scores = OUTCOMES.map(|(_, outcome)| puzzle.rules.outcome_score(outcome));
+        let mut opponent = moves.iter().map(|m| Letter::default(m.opponent)).collect();
+        let mut player = moves.iter().map(|m| Letter::default(m.player)).collect();
+        let mut outcomes = puzzle.outcomes.map(Letter::default).to_vec();
+
+        let move_names = moves.iter().map(|m| m.name.clone()).collect::<Vec<_>>();
+        let move_index = |name: &str| move_names.iter().position(|m| m == name);
+        let outcome_index = |name: &str| OUTCOMES.iter().position(|&(o, _)| o == name);
+
+        let mut seen = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let line_number = i + 1;
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = finish(line, key_value("=")).map_err(|e| e.at_line(line_number))?;
+            let error = |part: &str, reason: String| {
+                ParseError::at(line, part, reason).at_line(line_number)
+            };
+            if seen.contains(&key) {
+                return Err(error(key, format!("`{key}` given twice")));
+            }
+            seen.push(key);
+
+            let (section, name) = key.split_once('.').unwrap_or((key, ""));
+            if section == "score" {
+                let score = finish(value, signed::<i32>)
+                    .map_err(|e| e.within(line, value).at_line(line_number))?;
+                match (move_index(name), outcome_index(name)) {
+                    (Some(m), _) => moves[m].score = score,
+                    (_, Some(o)) => outcome_scores[o] = score,
+                    _ => return Err(error(key, format!("unknown move or outcome `{name}`"))),
+                }
+                continue;
+            }
+
+            let (letters, index) = match section {
+                "opponent" => (&mut opponent, move_index(name)),
+                "player" => (&mut player, move_index(name)),
+                "outcome" => (&mut outcomes, outcome_index(name)),
+                _ => return Err(error(key, format!("unknown key `{key}`"))),
+            };
+            let Some(index) = index else {
+                return Err(error(key, format!("unknown {section} `{name}`")));
+            };
+            let mut chars = value.chars();
+            let (Some(letter), None) = (chars.next(), chars.next()) else {
+                return Err(error(value, "expected a single letter".to_string()));
+            };
+            letters[index] = Letter {
+                letter,
+                set: Some((line_number, line, value)),
+            };
+        }
+
+        check_letters("opponent", &opponent)?;
+        check_letters("player", &player)?;
+        check_letters("outcome", &outcomes)?;
+        for (m, (opponent, player)) in moves.iter_mut().zip(opponent.iter().zip(&player)) {
+            (m.opponent, m.player) = (opponent.letter, player.letter);
+        }
+
+        let mut rules = puzzle.rules.with_moves(moves).expect("letters checked");
+        [rules.loss, rules.draw, rules.win] = outcome_scores;
+        Ok(Self {
+            rules,
+            outcomes: [0, 1, 2].map(|o| outcomes[o].letter),
+        })
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// The move of the opponent written `letter`, in any case.
+    pub fn opponent_move(&self, letter: char) -> Option<Move> {
+        self.rules.opponent_move(letter).map(Move::from_index)
+    }
+
+    /// The move of the player written `letter`, in any case.
+    pub fn player_move(&self, letter: char) -> Option<Move> {
+        self.rules.player_move(letter).map(Move::from_index)
+    }
+
+    /// The outcome written `letter`, in any case.
+    pub fn outcome(&self, letter: char) -> Option<Outcome> {
+        let index = self
+            .outcomes
+            .iter()
+            .position(|l| l.eq_ignore_ascii_case(&letter))?;
+        Some(OUTCOMES[index].1)
+    }
+
+    /// Score of playing `own`, whatever the outcome.
+    pub fn move_score(&self, own: Move) -> i32 {
+        self.rules.moves()[own.index()].score
+    }
+
+    /// Score of getting `outcome`, on top of the move played.
+    pub fn outcome_score(&self, outcome: Outcome) -> i32 {
+        self.rules.outcome_score(outcome)
+    }
+
+    /// Reads a strategy guide, whose second column can be a move, an outcome or both.
+    pub fn parse_guide<'a>(&self, input: &'a str) -> Result<Vec<Round<'a>>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| self.parse_round(line, i + 1).map_err(|e| e.at_line(i + 1)))
+            .collect()
+    }
+
+    // Reads line `number` of a strategy guide.
+    fn parse_round<'a>(&self, line: &'a str, number: usize) -> Result<Round<'a>, ParseError> {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let [opponent, response] = fields[..] else {
+            return Err(ParseError::new(
+                line,
+                "expected the opponent's move and a response",
+            ));
+        };
+
+        let letter = |field: &str| {
+            let mut chars = field.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => None,
+            }
+        };
+        let moves = self.rules.moves();
+        let opponent_move = letter(opponent)
+            .and_then(|c| self.opponent_move(c))
+            .ok_or_else(|| {
+                unknown(
+                    line,
+                    opponent,
+                    "move",
+                    listed(moves.iter().map(|m| m.opponent)),
+                )
+            })?;
+
+        let own = letter(response).and_then(|c| self.player_move(c));
+        let outcome = letter(response).and_then(|c| self.outcome(c));
+        if own.is_none() && outcome.is_none() {
+            let mut letters = moves.iter().map(|m| m.player).collect::<Vec<_>>();
+            for o in self.outcomes {
+                if !letters.iter().any(|l| l.eq_ignore_ascii_case(&o)) {
+                    letters.push(o);
+                }
+            }
+            let letters = listed(letters.into_iter());
+            return Err(unknown(line, response, "move or outcome", letters));
+        }
+        Ok(Round {
+            line,
+            number,
+            response,
+            opponent: opponent_move,
+            own,
+            outcome,
+        })
+    }
+
+    /// Moves of the opponent and the player in every round, the second column being the
+    /// player's moves.
+    pub fn as_moves(&self, rounds: &[Round]) -> Result<Vec<(Move, Move)>, ParseError> {
+        rounds
+            .iter()
+            .map(|round| {
+                let own = round.own.ok_or_else(|| {
+                    let letters = listed(self.rules.moves().iter().map(|m| m.player));
+                    unknown(round.line, round.response, "move", letters).at_line(round.number)
+                })?;
+                Ok((round.opponent, own))
+            })
+            .collect()
+    }
+
+    /// Moves of the opponent and the player in every round, the second column being the
+    /// outcomes to get.
+    pub fn as_outcomes(&self, rounds: &[Round]) -> Result<Vec<(Move, Move)>, ParseError> {
+        rounds
+            .iter()
+            .map(|round| {
+                let outcome = round.outcome.ok_or_else(|| {
+                    let letters = listed(self.outcomes.into_iter());
+                    unknown(round.line, round.response, "outcome", letters).at_line(round.number)
+                })?;
+                Ok((round.opponent, round.opponent.for_outcome(outcome)))
+            })
+            .collect()
+    }
+
+    /// Total score of the second column of `rounds` being the player's moves.
+    pub fn part_one(&self, rounds: &[Round]) -> Result<i32, ParseError> {
+        Ok(compute_scores(self, &self.as_moves(rounds)?).iter().sum())
+    }
+
+    /// Total score of the second column of `rounds` being the outcomes to get.
+    pub fn part_two(&self, rounds: &[Round]) -> Result<i32, ParseError> {
+        Ok(compute_scores(self, &self.as_outcomes(rounds)?)
+            .iter()
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::Solution;
+
+    const EXAMPLE: &str = include_str!("input_data/test.txt");
+
+    #[test]
+    fn defaults() {
+        let config = Config::parse("# The puzzle.\n\n").unwrap();
+        assert_eq!(config, Config::default());
+
+        let rounds = config.parse_guide(EXAMPLE).unwrap();
+        assert_eq!(
+            (config.part_one(&rounds), config.part_two(&rounds)),
+            (Ok(15), Ok(12))
+        );
+        assert_eq!(config.outcome('y'), Some(Outcome::Draw));
+        assert_eq!(config.player_move('A'), None);
+    }
+
+    #[test]
+    fn custom() {
+        let config = Config::parse(
+            "score.rock = 10\n\
+             score.win = 100\n\
+             # Swapped letters.\n\
+             opponent.rock = B\n\
+             opponent.paper = A\n\
+             player.scissors = Q\n\
+             outcome.lose = Z\n\
+             outcome.win = X",
+        )
+        .unwrap();
+        assert_eq!(config.opponent_move('B'), Some(Move::Rock));
+        assert_eq!(config.player_move('Q'), Some(Move::Scissors));
+        assert_eq!(config.outcome('X'), Some(Outcome::Win));
+        assert_eq!(
+            (
+                config.move_score(Move::Rock),
+                config.outcome_score(Outcome::Win)
+            ),
+            (10, 100)
+        );
+        assert_eq!(
+            compute_scores(&config, &[(Move::Scissors, Move::Rock)]),
+            [110]
+        );
+
+        // Z only is an outcome, Q only a move, so only the part reading them so scores them.
+        let outcomes = config.parse_guide("B Y\nA Z").unwrap();
+        assert_eq!(config.part_two(&outcomes), Ok(13 + 10));
+        let e = config.part_one(&outcomes).unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 3));
+        assert_eq!(e.reason(), "unknown move, expected one of X, Y, Q");
+
+        let moves = config.parse_guide("A Q").unwrap();
+        assert_eq!(config.part_one(&moves), Ok(103));
+        let e = config.part_two(&moves).unwrap_err();
+        assert_eq!(e.reason(), "unknown outcome, expected one of Z, Y, X");
+
+        let e = config.parse_guide("A Y\nA W").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 3));
+        assert_eq!(
+            e.reason(),
+            "unknown move or outcome, expected one of X, Y, Q, Z"
+        );
+
+        // Rock against rock, then losing with rock against paper and with paper against
+        // scissors, or winning twice and drawing.
+        let rounds = config.parse_guide("B X\nA X\nC Y").unwrap();
+        assert_eq!(config.part_one(&rounds), Ok(13 + 10 + 2));
+        assert_eq!(config.part_two(&rounds), Ok(102 + 103 + 6));
+        assert_ne!(config.part_one(&rounds), Ok(Day02::part_one(&rounds)));
+    }
+
+    #[test]
+    fn invalid() {
+        let e = Config::parse("score.rock = 1\nscore.rok = 2").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 1));
+
+        let e = Config::parse("  score.win = 1x").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(1), 15));
+
+        let e = Config::parse("player.rock = Y").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(1), 15));
+        let e = Config::parse("outcome.draw = w\n\noutcome.win = W").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(3), 15));
+
+        assert!(Config::parse("player.rock = XY").is_err());
+        assert!(Config::parse("player.rock =").is_err());
+        assert!(Config::parse("player.rock X").is_err());
+        assert!(Config::parse("score.rock = 1\nscore.rock = 2").is_err());
+        assert!(Config::parse("theme = dark").is_err());
+        assert!(Config::parse("outcome.rock = X").is_err());
+    }
+}
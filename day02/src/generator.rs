@@ -0,0 +1,20 @@
+use crate::Day02;
+use aoc_common::{rng::Rng, Generate};
+
+impl Generate for Day02 {
+    const SIZE: usize = 2500;
+
+    // `size` rounds of the strategy guide.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.choose(&['A', 'B', 'C']),
+                    rng.choose(&['X', 'Y', 'Z'])
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
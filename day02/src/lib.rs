@@ -0,0 +1,191 @@
+pub mod analyzer;
+pub mod config;
+mod generator;
+pub mod rules;
+pub mod tournament;
+
+use aoc_common::{ParseError, Solution};
+use config::Config;
+use rules::{Outcome, Rules};
+use std::{
+    fmt::{self, Display},
+    sync::OnceLock,
+};
+
+// How the puzzle reads and scores the guide.
+fn puzzle() -> &'static Config {
+    static PUZZLE: OnceLock<Config> = OnceLock::new();
+    PUZZLE.get_or_init(Config::default)
+}
+
+// Which move beats which, the moves being in the order of `Move`. Configurations only change
+// the letters and scores of the moves, so every one of them plays by these.
+fn classic() -> &'static Rules {
+    static CLASSIC: OnceLock<Rules> = OnceLock::new();
+    CLASSIC.get_or_init(Rules::classic)
+}
+
+/// A move of the puzzle. Moves beat each other in a cycle, so they are not ordered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    const ALL: [Move; 3] = [Move::Rock, Move::Paper, Move::Scissors];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        Self::ALL[index]
+    }
+
+    /// Outcome of playing this move against `other`.
+    pub fn play(self, other: Move) -> Outcome {
+        classic().play(self.index(), other.index())
+    }
+
+    /// The move getting `outcome` against this one.
+    pub fn for_outcome(self, outcome: Outcome) -> Move {
+        Self::from_index(classic().for_outcome(self.index(), outcome))
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&classic().moves()[self.index()].name)
+    }
+}
+
+fn compute_scores(config: &Config, moves: &[(Move, Move)]) -> Vec<i32> {
+    moves
+        .iter()
+        .map(|&(opponent_move, own_move)| {
+            config.move_score(own_move) + config.outcome_score(own_move.play(opponent_move))
+        })
+        .collect()
+}
+
+/// One line of the strategy guide, as read by a [`Config`]: the opponent's move, and the
+/// second column as the player's move or as the outcome to get, whichever it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round<'a> {
+    // The line, its number and its second column, for errors about the reading it lacks.
+    line: &'a str,
+    number: usize,
+    response: &'a str,
+    opponent: Move,
+    own: Option<Move>,
+    outcome: Option<Outcome>,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Round<'a>>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        puzzle().parse_guide(input)
+    }
+
+    // The puzzle's letters are both moves and outcomes, so its rounds have both readings.
+    fn part_one(rounds: &Self::Input<'_>) -> Self::PartOne {
+        puzzle()
+            .part_one(rounds)
+            .expect("rounds read by `Day02::parse`")
+    }
+
+    fn part_two(rounds: &Self::Input<'_>) -> Self::PartTwo {
+        puzzle()
+            .part_two(rounds)
+            .expect("rounds read by `Day02::parse`")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{rng::Rng, Generate};
+
+    #[test]
+    fn rps_rock_beats_scissors() {
+        assert_eq!(Move::Rock.play(Move::Scissors), Outcome::Win);
+        assert_eq!(Move::Scissors.play(Move::Rock), Outcome::Lose);
+    }
+
+    #[test]
+    fn rps_paper_beats_rock() {
+        assert_eq!(Move::Paper.play(Move::Rock), Outcome::Win);
+        assert_eq!(Move::Rock.play(Move::Paper), Outcome::Lose);
+    }
+
+    #[test]
+    fn rps_scissors_beats_paper() {
+        assert_eq!(Move::Scissors.play(Move::Paper), Outcome::Win);
+        assert_eq!(Move::Paper.play(Move::Scissors), Outcome::Lose);
+    }
+
+    #[test]
+    fn rps_same_moves_draw() {
+        for m in Move::ALL {
+            assert_eq!(m.play(m), Outcome::Draw);
+        }
+    }
+
+    #[test]
+    fn rps_for_outcome() {
+        assert_eq!(Move::Rock.for_outcome(Outcome::Win), Move::Paper);
+        assert_eq!(Move::Rock.for_outcome(Outcome::Lose), Move::Scissors);
+        assert_eq!(Move::Scissors.for_outcome(Outcome::Draw), Move::Scissors);
+        for m in Move::ALL {
+            for outcome in Outcome::ALL {
+                assert_eq!(m.for_outcome(outcome).play(m), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn day02_a() {
+        let input = Day02::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day02::part_one(&input), 15);
+    }
+
+    #[test]
+    fn day02_b() {
+        let input = Day02::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day02::part_two(&input), 12);
+    }
+
+    #[test]
+    fn day02_invalid() {
+        let e = Day02::parse("A Y\nB Q").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (Some(2), 3, "B Q"));
+
+        let e = Day02::parse("A Y Z").unwrap_err();
+        assert_eq!(e.line(), Some(1));
+    }
+
+    #[test]
+    fn day02_generated() {
+        // Every pair of moves shows up in a guide this long.
+        for seed in 0..10 {
+            let input = Day02::generate(&mut Rng::new(seed), Day02::SIZE);
+            let rounds = Day02::parse(&input).unwrap();
+            let moves = Config::default().as_moves(&rounds).unwrap();
+            assert_eq!(moves.len(), Day02::SIZE);
+            for opponent in Move::ALL {
+                assert!(Move::ALL
+                    .iter()
+                    .all(|&own| moves.contains(&(opponent, own))));
+            }
+        }
+    }
+}
@@ -1,219 +1,147 @@
-use std::{cmp::Ordering, str::FromStr};
-
-#[derive(Debug)]
-struct RpsFormatError;
-
-#[derive(Clone, Copy, Debug)]
-struct ParseMovesError;
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Move {
-    Rock,
-    Paper,
-    Scissors,
-}
-
-impl Into<i32> for Move {
-    fn into(self) -> i32 {
-        match self {
-            Self::Rock => 1,
-            Self::Paper => 2,
-            Self::Scissors => 3,
-        }
-    }
+use aoc_common::{
+    input::{self, InputSource},
+    Solution,
+};
+use day02::{
+    analyzer,
+    config::Config,
+    tournament::{self, Fixed, Frequency, Random, Replay, Strategy, WinStayLoseShift},
+    Day02, Move,
+};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: day02 [INPUT] [--config <FILE>] [--analyze | --tournament <ROUNDS>]
+
+Without options, prints the answers of both parts. `--config` reads the guide with the scores
+and letters of FILE, `key = value` lines such as `score.rock = 1`, `score.win = 6`,
+`opponent.rock = A`, `player.rock = X` or `outcome.lose = X`. Keys left out keep the values of
+the puzzle. `--analyze` scores every assignment of the player's letters to moves and to
+outcomes, with the best and worst ones round by round. `--tournament` plays matches of ROUNDS
+rounds between every two built-in strategies, one of them replaying the guide, and prints the
+standings.";
+
+// What to print about the guide.
+enum Query {
+    Answers,
+    Analyze,
+    Tournament(usize),
 }
 
-impl FromStr for Move {
-    type Err = ParseMovesError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_uppercase().as_str() {
-            "A" => Ok(Self::Rock),
-            "X" => Ok(Self::Rock),
-            "B" => Ok(Self::Paper),
-            "Y" => Ok(Self::Paper),
-            "C" => Ok(Self::Scissors),
-            "Z" => Ok(Self::Scissors),
-            _ => Err(ParseMovesError),
-        }
-    }
-}
-
-impl PartialOrd for Move {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self == other {
-            Some(Ordering::Equal)
-        } else {
-            if *self == Self::Rock && *other == Self::Scissors
-                || *self == Self::Scissors && *other == Self::Paper
-                || *self == Self::Paper && *other == Self::Rock
-            {
-                Some(Ordering::Greater)
-            } else {
-                Some(Ordering::Less)
-            }
-        }
-    }
-}
-
-fn compute_scores(moves: &Vec<(Move, Move)>) -> Vec<i32> {
-    moves
-        .iter()
-        .map(|m| {
-            let (opponent_move, own_move) = m;
-            let mut own_value: i32 = (*own_move).into();
-
-            if own_move == opponent_move {
-                own_value += 3;
-            } else if own_move > opponent_move {
-                own_value += 6;
-            }
-
-            own_value
-        })
-        .collect()
-}
-
-fn solve_a(input: &str) -> i32 {
-    let lines = input.split("\n");
-    let moves = lines
-        .map(|a| {
-            let moves: Vec<Move> = a
-                .split_whitespace()
-                .map(|b| b.parse::<Move>().unwrap())
-                .collect();
-            match &moves[..] {
-                &[opponent, own] => Ok((opponent, own)),
-                _ => Err(RpsFormatError),
-            }
-            .unwrap()
-        })
-        .collect::<Vec<(Move, Move)>>();
-
-    let moves_scores = compute_scores(&moves);
-    moves_scores.iter().sum()
-}
-
-#[derive(Debug)]
-struct ParseCommandError;
-
-#[derive(Debug)]
-enum Command {
-    Win,
-    Lose,
-    Tie,
+// Input and configuration file named on the command line, and what to print.
+struct Args {
+    source: Option<InputSource>,
+    config: Option<String>,
+    query: Query,
 }
 
-impl Command {
-    fn process(&self, m: Move) -> Move {
-        match &self {
-            Self::Tie => m.clone(),
-            Self::Win => match m {
-                Move::Rock => Move::Paper,
-                Move::Paper => Move::Scissors,
-                Move::Scissors => Move::Rock,
+fn parse_args() -> Result<Option<Args>, String> {
+    let mut args = std::env::args().skip(1);
+    let (mut source, mut config, mut query) = (None, None, Query::Answers);
+
+    while let Some(arg) = args.next() {
+        let next = match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--config" => match args.next() {
+                Some(path) => {
+                    config = Some(path);
+                    continue;
+                }
+                None => return Err("`--config` takes a file".to_string()),
             },
-            Self::Lose => match m {
-                Move::Rock => Move::Scissors,
-                Move::Paper => Move::Rock,
-                Move::Scissors => Move::Paper,
+            "--analyze" => Query::Analyze,
+            "--tournament" => match args.next().map(|n| n.parse::<usize>()) {
+                Some(Ok(rounds)) if rounds > 0 => Query::Tournament(rounds),
+                _ => return Err("`--tournament` takes a positive number of rounds".to_string()),
             },
+            _ if source.is_none() => {
+                source = Some(InputSource::from_arg(&arg));
+                continue;
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        };
+        if !matches!(query, Query::Answers) {
+            return Err("`--analyze` and `--tournament` cannot be combined".to_string());
         }
+        query = next;
     }
+    Ok(Some(Args {
+        source,
+        config,
+        query,
+    }))
 }
 
-impl FromStr for Command {
-    type Err = ParseCommandError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "X" => Ok(Self::Lose),
-            "Y" => Ok(Self::Tie),
-            "Z" => Ok(Self::Win),
-            _ => Err(ParseCommandError),
-        }
+// The built-in strategies, and the replay of the guide unless empty.
+fn players(guide: Option<Replay>) -> Vec<Box<dyn Strategy>> {
+    let mut players: Vec<Box<dyn Strategy>> = vec![
+        Box::new(Fixed::new(vec![Move::Rock])),
+        Box::new(Fixed::new(vec![Move::Rock, Move::Paper, Move::Scissors])),
+        Box::new(Random::new(1)),
+        Box::new(Frequency),
+        Box::new(WinStayLoseShift::new(Move::Rock)),
+    ];
+    if let Some(replay) = guide {
+        players.push(Box::new(replay));
     }
+    players
 }
 
-fn solve_b(input: &str) -> i32 {
-    let lines = input.lines();
-    let moves = lines
-        .map(|line| {
-            let fields: Vec<&str> = line.split_whitespace().collect();
-            match &fields[..] {
-                &[opponent_move, command] => Ok((
-                    opponent_move.parse::<Move>().unwrap(),
-                    command.parse::<Command>().unwrap(),
-                )),
-                _ => Err(RpsFormatError),
-            }
-            .unwrap()
-        })
-        .map(|line| {
-            let (opponent_move, command) = line;
-            (opponent_move, command.process(opponent_move))
-        })
-        .collect::<Vec<(Move, Move)>>();
-
-    let moves_scores = compute_scores(&moves);
-    moves_scores.iter().sum()
-}
-
-fn main() {
-    let input = include_str!("input_data/input.txt");
-    println!("Part 1: {}", solve_a(input));
-    println!("Part 2: {}", solve_b(input));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    #[ignore]
-    fn rps_rock_gt_scissors() {
-        assert!(Move::Rock > Move::Scissors);
-    }
-
-    #[test]
-    #[ignore]
-    fn rps_paper_gt_rock() {
-        assert!(Move::Paper > Move::Rock);
-    }
-
-    #[test]
-    #[ignore]
-    fn rps_scissors_gt_paper() {
-        assert!(Move::Scissors > Move::Paper);
-    }
-
-    #[test]
-    #[ignore]
-    fn rps_rock_eq_rock() {
-        assert!(Move::Rock == Move::Rock);
-    }
-
-    #[test]
-    #[ignore]
-    fn rps_paper_eq_paper() {
-        assert!(Move::Paper == Move::Paper);
-    }
-
-    #[test]
-    #[ignore]
-    fn rps_scissors_eq_scissors() {
-        assert!(Move::Scissors == Move::Scissors);
-    }
-
-    #[test]
-    fn day02_a() {
-        let input = include_str!("input_data/test.txt");
-        assert_eq!(solve_a(input), 15);
-    }
+fn main() -> ExitCode {
+    let Args {
+        source,
+        config,
+        query,
+    } = match parse_args() {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => return aoc_common::exit(Err(format!("{e}\n\n{USAGE}"))),
+    };
 
-    #[test]
-    fn day02_b() {
-        let input = include_str!("input_data/test.txt");
-        assert_eq!(solve_b(input), 12);
+    let source = source.unwrap_or_else(InputSource::from_env);
+    // The puzzle's letters and scores, answered like every other day.
+    if let (None, Query::Answers) = (&config, &query) {
+        return aoc_common::main_from::<Day02>(&source);
     }
+    let config = match config.map(|path| (std::fs::read_to_string(&path), path)) {
+        None => Config::default(),
+        Some((Ok(text), path)) => match Config::parse(&text) {
+            Ok(config) => config,
+            Err(e) => return aoc_common::exit(Err(format!("in `{path}`, {e}"))),
+        },
+        Some((Err(e), path)) => return aoc_common::exit(Err(format!("cannot read `{path}`: {e}"))),
+    };
+
+    let input = match input::load(Day02::DAY, &source) {
+        Ok(input) => input,
+        Err(e) => return aoc_common::exit(Err(e)),
+    };
+    let rounds = match config.parse_guide(&input) {
+        Ok(rounds) => rounds,
+        Err(e) => return aoc_common::exit(Err(e.in_day(Day02::DAY))),
+    };
+
+    let result = match query {
+        // Each part fails alone when the guide's letters do not read as it needs.
+        Query::Answers => {
+            let (one, two) = (config.part_one(&rounds), config.part_two(&rounds));
+            for (part, answer) in [(1, &one), (2, &two)] {
+                if let Ok(score) = answer {
+                    println!("Part {part}: {score}");
+                }
+            }
+            one.and(two).map(|_| ())
+        }
+        Query::Analyze => analyzer::analyze(&config, &rounds).map(|analysis| print!("{analysis}")),
+        Query::Tournament(length) => config.as_outcomes(&rounds).map(|guide| {
+            let mut players = players(Replay::new(&guide));
+            let standings = tournament::round_robin(&config, &mut players, length);
+            for (rank, standing) in standings.iter().enumerate() {
+                println!("{}. {standing}", rank + 1);
+            }
+        }),
+    };
+    aoc_common::exit(result.map_err(|e| e.in_day(Day02::DAY)))
 }
@@ -0,0 +1,347 @@
+use std::fmt::{self, Display};
+
+/// How a round ends, for one of its players.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Win, Outcome::Lose, Outcome::Draw];
+
+    /// The outcome of the same round for the other player.
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Win => Self::Lose,
+            Self::Lose => Self::Win,
+            Self::Draw => Self::Draw,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Win => "win",
+            Self::Lose => "lose",
+            Self::Draw => "draw",
+        })
+    }
+}
+
+/// A move of the game: its name, the letters standing for it in the two columns of a strategy
+/// guide, and the score it earns whoever plays it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveSpec {
+    pub name: String,
+    pub opponent: char,
+    pub player: char,
+    pub score: i32,
+}
+
+impl MoveSpec {
+    pub fn new(name: &str, opponent: char, player: char, score: i32) -> Self {
+        Self {
+            name: name.to_string(),
+            opponent,
+            player,
+            score,
+        }
+    }
+}
+
+/// Rules of a rock-paper-scissors-like game. Moves are their position in the move set, and
+/// each of them beats exactly half of the others.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    moves: Vec<MoveSpec>,
+    // `beats[a][b]` when `a` beats `b`.
+    beats: Vec<Vec<bool>>,
+    /// Scores of a lost, drawn and won round, on top of the score of the move played.
+    pub loss: i32,
+    pub draw: i32,
+    pub win: i32,
+}
+
+impl Rules {
+    /// Rules of a game where each `(winner, loser)` pair of move names says which move beats
+    /// which. Every two moves have to be in one pair, each move winning as often as it loses.
+    pub fn new(moves: Vec<MoveSpec>, wins: &[(&str, &str)]) -> Result<Self, String> {
+        let n = moves.len();
+        if n < 3 || n.is_multiple_of(2) {
+            return Err(format!("{n} moves, expected an odd number of at least 3"));
+        }
+        for (i, a) in moves.iter().enumerate() {
+            for b in &moves[..i] {
+                if a.name == b.name {
+                    return Err(format!("move `{}` defined twice", a.name));
+                }
+                if a.opponent.eq_ignore_ascii_case(&b.opponent)
+                    || a.player.eq_ignore_ascii_case(&b.player)
+                {
+                    return Err(format!("`{}` and `{}` share a letter", b.name, a.name));
+                }
+            }
+        }
+
+        let find = |name: &str| {
+            moves
+                .iter()
+                .position(|m| m.name == name)
+                .ok_or_else(|| format!("unknown move `{name}`"))
+        };
+        let mut beats = vec![vec![false; n]; n];
+        for &(winner, loser) in wins {
+            let (w, l) = (find(winner)?, find(loser)?);
+            if w == l || beats[w][l] || beats[l][w] {
+                return Err(format!(
+                    "`{winner}` beating `{loser}` given twice or both ways"
+                ));
+            }
+            beats[w][l] = true;
+        }
+
+        for (m, row) in moves.iter().zip(&beats) {
+            let won = row.iter().filter(|&&b| b).count();
+            if won != n / 2 {
+                return Err(format!(
+                    "`{}` beats {won} moves, expected {}",
+                    m.name,
+                    n / 2
+                ));
+            }
+        }
+
+        Ok(Self {
+            moves,
+            beats,
+            loss: 0,
+            draw: 3,
+            win: 6,
+        })
+    }
+
+    /// Rock, paper, scissors, as the puzzle plays it.
+    pub fn classic() -> Self {
+        let moves = vec![
+            MoveSpec::new("rock", 'A', 'X', 1),
+            MoveSpec::new("paper", 'B', 'Y', 2),
+            MoveSpec::new("scissors", 'C', 'Z', 3),
+        ];
+        let wins = [
+            ("rock", "scissors"),
+            ("scissors", "paper"),
+            ("paper", "rock"),
+        ];
+        Self::new(moves, &wins).unwrap()
+    }
+
+    /// Rock, paper, scissors, lizard, Spock, with the opponent's moves written A to E and the
+    /// player's V to Z.
+    pub fn rpsls() -> Self {
+        let moves = vec![
+            MoveSpec::new("rock", 'A', 'V', 1),
+            MoveSpec::new("paper", 'B', 'W', 2),
+            MoveSpec::new("scissors", 'C', 'X', 3),
+            MoveSpec::new("lizard", 'D', 'Y', 4),
+            MoveSpec::new("spock", 'E', 'Z', 5),
+        ];
+        let wins = [
+            ("scissors", "paper"),
+            ("paper", "rock"),
+            ("rock", "lizard"),
+            ("lizard", "spock"),
+            ("spock", "scissors"),
+            ("scissors", "lizard"),
+            ("lizard", "paper"),
+            ("paper", "spock"),
+            ("spock", "rock"),
+            ("rock", "scissors"),
+        ];
+        Self::new(moves, &wins).unwrap()
+    }
+
+    /// The same game with other letters and scores for its moves, given in the same order.
+    pub fn with_moves(&self, moves: Vec<MoveSpec>) -> Result<Self, String> {
+        if moves.len() != self.moves.len() {
+            return Err(format!(
+                "{} moves, expected {}",
+                moves.len(),
+                self.moves.len()
+            ));
+        }
+
+        let mut wins = vec![];
+        for (a, row) in self.beats.iter().enumerate() {
+            for b in (0..row.len()).filter(|&b| row[b]) {
+                wins.push((moves[a].name.clone(), moves[b].name.clone()));
+            }
+        }
+        let wins = wins
+            .iter()
+            .map(|(a, b)| (a.as_str(), b.as_str()))
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            loss: self.loss,
+            draw: self.draw,
+            win: self.win,
+            ..Self::new(moves, &wins)?
+        })
+    }
+
+    pub fn moves(&self) -> &[MoveSpec] {
+        &self.moves
+    }
+
+    /// The move named `name`.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.moves.iter().position(|m| m.name == name)
+    }
+
+    /// The move of the opponent written `letter`, in any case.
+    pub fn opponent_move(&self, letter: char) -> Option<usize> {
+        self.moves
+            .iter()
+            .position(|m| m.opponent.eq_ignore_ascii_case(&letter))
+    }
+
+    /// The move of the player written `letter`, in any case.
+    pub fn player_move(&self, letter: char) -> Option<usize> {
+        self.moves
+            .iter()
+            .position(|m| m.player.eq_ignore_ascii_case(&letter))
+    }
+
+    pub fn beats(&self, a: usize, b: usize) -> bool {
+        self.beats[a][b]
+    }
+
+    /// Outcome of playing `own` against `opponent`.
+    pub fn play(&self, own: usize, opponent: usize) -> Outcome {
+        if own == opponent {
+            Outcome::Draw
+        } else if self.beats(own, opponent) {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// First move, in the move set, getting `outcome` against `opponent`.
+    pub fn for_outcome(&self, opponent: usize, outcome: Outcome) -> usize {
+        (0..self.moves.len())
+            .find(|&own| self.play(own, opponent) == outcome)
+            .unwrap()
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> i32 {
+        match outcome {
+            Outcome::Win => self.win,
+            Outcome::Lose => self.loss,
+            Outcome::Draw => self.draw,
+        }
+    }
+
+    /// Score of the player playing `own` against `opponent`.
+    pub fn score(&self, opponent: usize, own: usize) -> i32 {
+        self.moves[own].score + self.outcome_score(self.play(own, opponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets() {
+        for rules in [Rules::classic(), Rules::rpsls()] {
+            let n = rules.moves().len();
+            for a in 0..n {
+                assert!(!rules.beats(a, a));
+                for b in 0..n {
+                    assert_eq!(rules.play(a, b), rules.play(b, a).reverse());
+                }
+                for outcome in Outcome::ALL {
+                    assert_eq!(rules.play(rules.for_outcome(a, outcome), a), outcome);
+                }
+                assert_eq!(rules.score(a, a), rules.moves()[a].score + rules.draw);
+            }
+        }
+
+        let classic = Rules::classic();
+        let [rock, paper, scissors] =
+            ["rock", "paper", "scissors"].map(|m| classic.find(m).unwrap());
+        assert!(classic.beats(rock, scissors) && classic.beats(paper, rock));
+        assert_eq!(classic.for_outcome(scissors, Outcome::Win), rock);
+        assert_eq!(classic.for_outcome(rock, Outcome::Lose), scissors);
+        assert_eq!(classic.score(rock, paper), 8);
+        assert_eq!(classic.score(paper, rock), 1);
+
+        let rpsls = Rules::rpsls();
+        let [spock, lizard] = ["spock", "lizard"].map(|m| rpsls.find(m).unwrap());
+        assert!(rpsls.beats(lizard, spock));
+        assert_eq!(
+            rpsls.for_outcome(spock, Outcome::Win),
+            rpsls.find("paper").unwrap()
+        );
+    }
+
+    #[test]
+    fn letters() {
+        let classic = Rules::classic();
+        assert_eq!(classic.opponent_move('b'), Some(1));
+        assert_eq!(classic.player_move('Z'), Some(2));
+        assert_eq!(classic.player_move('A'), None);
+
+        let rpsls = Rules::rpsls();
+        let [spock, lizard] = ['e', 'D'].map(|letter| rpsls.opponent_move(letter).unwrap());
+        let [paper, scissors] = ['W', 'x'].map(|letter| rpsls.player_move(letter).unwrap());
+        assert_eq!(rpsls.score(spock, paper), 2 + 6);
+        assert_eq!(rpsls.score(lizard, scissors), 3 + 6);
+        assert_eq!(
+            (rpsls.opponent_move('F'), rpsls.player_move('A')),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let moves = |names: &[&str]| {
+            names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    MoveSpec::new(name, (b'A' + i as u8) as char, (b'Q' + i as u8) as char, 1)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(Rules::new(moves(&["a", "b"]), &[("a", "b")]).is_err());
+        assert!(Rules::new(moves(&["a", "b", "c"]), &[("a", "b"), ("b", "c")]).is_err());
+        assert!(Rules::new(
+            moves(&["a", "b", "c"]),
+            &[("a", "b"), ("b", "a"), ("c", "a")]
+        )
+        .is_err());
+        assert!(Rules::new(
+            moves(&["a", "b", "c"]),
+            &[("a", "b"), ("b", "c"), ("c", "d")]
+        )
+        .is_err());
+        assert!(Rules::new(moves(&["a", "a", "c"]), &[]).is_err());
+        // Transitive rather than cyclic.
+        assert!(Rules::new(
+            moves(&["a", "b", "c"]),
+            &[("a", "b"), ("b", "c"), ("a", "c")]
+        )
+        .is_err());
+        assert!(Rules::new(
+            moves(&["a", "b", "c"]),
+            &[("a", "b"), ("b", "c"), ("c", "a")]
+        )
+        .is_ok());
+    }
+}
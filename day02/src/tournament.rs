@@ -0,0 +1,339 @@
+//! Players choosing their moves round by round, meeting each other in round-robin tournaments.
+
+use crate::{compute_scores, config::Config, rules::Outcome, Move};
+use aoc_common::rng::Rng;
+use std::{
+    cmp::{Ordering, Reverse},
+    fmt::{self, Display},
+};
+
+/// A way of playing, move after move.
+pub trait Strategy {
+    fn name(&self) -> String;
+
+    /// Move of the next round, `history` holding the moves of the opponent and of this player
+    /// in every round of the match so far.
+    fn next_move(&mut self, history: &[(Move, Move)]) -> Move;
+
+    /// Starts over before a new match, for strategies keeping a state of their own.
+    fn reset(&mut self) {}
+}
+
+// Moves separated by commas, for names.
+fn listed(moves: &[Move]) -> String {
+    moves
+        .iter()
+        .map(Move::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Plays the same moves over and over.
+pub struct Fixed {
+    moves: Vec<Move>,
+}
+
+impl Fixed {
+    pub fn new(moves: Vec<Move>) -> Self {
+        assert!(!moves.is_empty(), "no moves to play");
+        Self { moves }
+    }
+}
+
+impl Strategy for Fixed {
+    fn name(&self) -> String {
+        format!("fixed {}", listed(&self.moves))
+    }
+
+    fn next_move(&mut self, history: &[(Move, Move)]) -> Move {
+        self.moves[history.len() % self.moves.len()]
+    }
+}
+
+/// Plays any move, the same ones for the same seed.
+pub struct Random {
+    seed: u64,
+    rng: Rng,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> String {
+        format!("random, seed {}", self.seed)
+    }
+
+    fn next_move(&mut self, _: &[(Move, Move)]) -> Move {
+        *self.rng.choose(&Move::ALL)
+    }
+
+    fn reset(&mut self) {
+        self.rng = Rng::new(self.seed);
+    }
+}
+
+/// Plays the move beating the one the opponent played the most, the first of them on ties.
+pub struct Frequency;
+
+impl Strategy for Frequency {
+    fn name(&self) -> String {
+        "frequency".to_string()
+    }
+
+    fn next_move(&mut self, history: &[(Move, Move)]) -> Move {
+        let count = |m: &Move| history.iter().filter(|(opponent, _)| opponent == m).count();
+        // `max_by_key` keeps the last of the highest counts.
+        let likely = Move::ALL.into_iter().rev().max_by_key(count).unwrap();
+        likely.for_outcome(Outcome::Win)
+    }
+}
+
+/// Plays its move again after winning, else the move beating it.
+pub struct WinStayLoseShift {
+    first: Move,
+}
+
+impl WinStayLoseShift {
+    pub fn new(first: Move) -> Self {
+        Self { first }
+    }
+}
+
+impl Strategy for WinStayLoseShift {
+    fn name(&self) -> String {
+        format!("win-stay/lose-shift from {}", self.first)
+    }
+
+    fn next_move(&mut self, history: &[(Move, Move)]) -> Move {
+        match history.last() {
+            None => self.first,
+            Some(&(opponent, own)) if own.play(opponent) == Outcome::Win => own,
+            Some(&(_, own)) => own.for_outcome(Outcome::Win),
+        }
+    }
+}
+
+/// Plays the moves of a strategy guide, its second column being the outcomes to get, over and
+/// over.
+pub struct Replay {
+    moves: Vec<Move>,
+}
+
+impl Replay {
+    /// Replay of the player's moves in `rounds`, none for an empty guide.
+    pub fn new(rounds: &[(Move, Move)]) -> Option<Self> {
+        let moves = rounds.iter().map(|&(_, own)| own).collect::<Vec<_>>();
+        (!moves.is_empty()).then_some(Self { moves })
+    }
+}
+
+impl Strategy for Replay {
+    fn name(&self) -> String {
+        format!("replay of {} rounds", self.moves.len())
+    }
+
+    fn next_move(&mut self, history: &[(Move, Move)]) -> Move {
+        self.moves[history.len() % self.moves.len()]
+    }
+}
+
+/// Results of a player over a tournament.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    /// Score of every round played.
+    pub score: i64,
+    /// Matches won, drawn and lost.
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Standing {
+    fn record(&mut self, score: i64, outcome: Outcome) {
+        self.score += score;
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Lose => self.losses += 1,
+        }
+    }
+}
+
+impl Display for Standing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} ({} won, {} drawn, {} lost)",
+            self.name, self.score, self.wins, self.draws, self.losses
+        )
+    }
+}
+
+// Scores of two players over a match of `rounds` rounds.
+fn play_match(
+    config: &Config,
+    a: &mut dyn Strategy,
+    b: &mut dyn Strategy,
+    rounds: usize,
+) -> (i64, i64) {
+    a.reset();
+    b.reset();
+    let (mut seen_by_a, mut seen_by_b) = (vec![], vec![]);
+    for _ in 0..rounds {
+        let (move_a, move_b) = (a.next_move(&seen_by_a), b.next_move(&seen_by_b));
+        seen_by_a.push((move_b, move_a));
+        seen_by_b.push((move_a, move_b));
+    }
+
+    let total = |moves| {
+        compute_scores(config, moves)
+            .into_iter()
+            .map(i64::from)
+            .sum()
+    };
+    (total(&seen_by_a), total(&seen_by_b))
+}
+
+/// Plays a match of `rounds` rounds between every two players, scored with `config`. Standings
+/// come highest score first, then most matches won, ties in the order of `players`.
+pub fn round_robin(
+    config: &Config,
+    players: &mut [Box<dyn Strategy>],
+    rounds: usize,
+) -> Vec<Standing> {
+    let mut standings = players
+        .iter()
+        .map(|player| Standing {
+            name: player.name(),
+            score: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        })
+        .collect::<Vec<_>>();
+
+    for j in 1..players.len() {
+        let (before, after) = players.split_at_mut(j);
+        for (i, a) in before.iter_mut().enumerate() {
+            let (score_a, score_b) = play_match(config, a.as_mut(), after[0].as_mut(), rounds);
+            let outcome = match score_a.cmp(&score_b) {
+                Ordering::Greater => Outcome::Win,
+                Ordering::Less => Outcome::Lose,
+                Ordering::Equal => Outcome::Draw,
+            };
+            standings[i].record(score_a, outcome);
+            standings[j].record(score_b, outcome.reverse());
+        }
+    }
+
+    standings.sort_by_key(|standing| Reverse((standing.score, standing.wins)));
+    standings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::Solution;
+
+    #[test]
+    fn strategies() {
+        let mut cycle = Fixed::new(vec![Move::Rock, Move::Paper]);
+        let history = [(Move::Rock, Move::Rock); 3];
+        assert_eq!(cycle.next_move(&history[..2]), Move::Rock);
+        assert_eq!(cycle.next_move(&history), Move::Paper);
+
+        let mut random = [Random::new(7), Random::new(7)];
+        let moves = random
+            .each_mut()
+            .map(|r| (0..20).map(|_| r.next_move(&[])).collect::<Vec<_>>());
+        assert_eq!(moves[0], moves[1]);
+        assert!(Move::ALL.iter().all(|m| moves[0].contains(m)));
+
+        let history = [
+            (Move::Scissors, Move::Rock),
+            (Move::Paper, Move::Rock),
+            (Move::Paper, Move::Rock),
+        ];
+        assert_eq!(Frequency.next_move(&[]), Move::Paper);
+        assert_eq!(Frequency.next_move(&history), Move::Scissors);
+        assert_eq!(Frequency.next_move(&history[..1]), Move::Rock);
+
+        let mut wsls = WinStayLoseShift::new(Move::Scissors);
+        assert_eq!(wsls.next_move(&[]), Move::Scissors);
+        assert_eq!(wsls.next_move(&history[..1]), Move::Rock);
+        assert_eq!(wsls.next_move(&history[..2]), Move::Paper);
+
+        let guide = Day02::parse(include_str!("input_data/test.txt")).unwrap();
+        let mut replay = Replay::new(&Config::default().as_outcomes(&guide).unwrap()).unwrap();
+        assert_eq!(replay.next_move(&history[..1]), Move::Rock);
+        assert!(Replay::new(&[]).is_none());
+    }
+
+    #[test]
+    fn tournament() {
+        let mut players: Vec<Box<dyn Strategy>> = vec![
+            Box::new(Fixed::new(vec![Move::Rock])),
+            Box::new(Fixed::new(vec![Move::Paper])),
+            Box::new(Frequency),
+        ];
+        let standings = round_robin(&Config::default(), &mut players, 10);
+
+        let results = standings
+            .iter()
+            .map(|s| (s.name.as_str(), s.score, s.wins, s.draws, s.losses))
+            .collect::<Vec<_>>();
+        // Frequency draws its first round against paper, then beats it with scissors.
+        assert_eq!(
+            results,
+            [
+                ("frequency", 80 + 5 + 9 * 9, 2, 0, 0),
+                ("fixed paper", 80 + 5 + 9 * 2, 1, 0, 1),
+                ("fixed rock", 10 + 10, 0, 0, 2),
+            ]
+        );
+        assert_eq!(
+            standings[2].to_string(),
+            "fixed rock: 20 (0 won, 0 drawn, 2 lost)"
+        );
+
+        let mut twins: Vec<Box<dyn Strategy>> =
+            vec![Box::new(Random::new(3)), Box::new(Random::new(3))];
+        let standings = round_robin(&Config::default(), &mut twins, 100);
+        assert_eq!(standings[0].score, standings[1].score);
+        assert_eq!((standings[0].draws, standings[1].draws), (1, 1));
+    }
+
+    #[test]
+    fn order() {
+        let players = || -> Vec<Box<dyn Strategy>> {
+            vec![
+                Box::new(Random::new(1)),
+                Box::new(Random::new(2)),
+                Box::new(Frequency),
+                Box::new(WinStayLoseShift::new(Move::Paper)),
+            ]
+        };
+        let by_name = |players: &mut [Box<dyn Strategy>]| {
+            let mut standings = round_robin(&Config::default(), players, 50);
+            standings.sort_by(|a, b| a.name.cmp(&b.name));
+            standings
+        };
+
+        let mut reversed = players();
+        reversed.reverse();
+        assert_eq!(by_name(&mut players()), by_name(&mut reversed));
+
+        // Playing again starts every match over.
+        let mut again = players();
+        assert_eq!(by_name(&mut again), by_name(&mut again));
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{
+    items::{Item, PriorityScheme},
+    read_items,
+};
+use aoc_common::ParseError;
+use std::fmt::Write;
+
+/// An item found in both compartments of a rucksack, with its count in each.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Misplaced {
+    pub item: Item,
+    pub left: usize,
+    pub right: usize,
+}
+
+/// What a rucksack of the input holds. Odd rucksacks have the extra item in their right
+/// compartment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Line of the rucksack, from 1.
+    pub line: usize,
+    pub items: usize,
+    /// Items in both compartments, by priority.
+    pub misplaced: Vec<Misplaced>,
+}
+
+impl Diagnosis {
+    pub fn is_empty(&self) -> bool {
+        self.items == 0
+    }
+
+    pub fn is_odd(&self) -> bool {
+        !self.items.is_multiple_of(2)
+    }
+
+    /// Whether the rucksack is as the puzzle describes it: two compartments of the same size
+    /// sharing a single item.
+    pub fn is_sound(&self) -> bool {
+        !self.is_empty() && !self.is_odd() && self.misplaced.len() == 1
+    }
+
+    // What is wrong with the rucksack, if anything.
+    fn flags(&self) -> Vec<String> {
+        if self.is_empty() {
+            return vec!["empty".to_string()];
+        }
+        let mut flags = vec![];
+        if self.is_odd() {
+            flags.push("odd length".to_string());
+        }
+        match self.misplaced.len() {
+            0 => flags.push("no misplaced item".to_string()),
+            1 => {}
+            n => flags.push(format!("{n} misplaced items")),
+        }
+        flags
+    }
+}
+
+// Number of items of each priority.
+fn counts(items: &[Item]) -> [usize; 64] {
+    let mut counts = [0; 64];
+    for item in items {
+        counts[item.priority() as usize] += 1;
+    }
+    counts
+}
+
+/// Every rucksack of `input`, its items written in `scheme`, whatever its length.
+pub fn diagnose(input: &str, scheme: &impl PriorityScheme) -> Result<Vec<Diagnosis>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, sack)| diagnose_sack(i + 1, sack, scheme).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+// Diagnosis of the rucksack on line `line`.
+fn diagnose_sack(
+    line: usize,
+    sack: &str,
+    scheme: &impl PriorityScheme,
+) -> Result<Diagnosis, ParseError> {
+    let items = read_items(sack, scheme).collect::<Result<Vec<_>, _>>()?;
+    let (left, right) = items.split_at(items.len() / 2);
+    let (left_counts, right_counts) = (counts(left), counts(right));
+
+    let misplaced = left
+        .iter()
+        .filter(|item| right_counts[item.priority() as usize] > 0)
+        .map(|&item| Misplaced {
+            item,
+            left: left_counts[item.priority() as usize],
+            right: right_counts[item.priority() as usize],
+        });
+    let mut misplaced = misplaced.collect::<Vec<_>>();
+    misplaced.sort_by_key(|m| m.item.priority());
+    misplaced.dedup();
+
+    Ok(Diagnosis {
+        line,
+        items: items.len(),
+        misplaced,
+    })
+}
+
+const HEADER: [&str; 4] = ["line", "items", "misplaced (left/right)", "flags"];
+
+fn fields(diagnosis: &Diagnosis) -> [String; 4] {
+    let misplaced = diagnosis
+        .misplaced
+        .iter()
+        .map(|m| format!("{} {}/{}", m.item.symbol(), m.left, m.right))
+        .collect::<Vec<_>>();
+    [
+        diagnosis.line.to_string(),
+        diagnosis.items.to_string(),
+        misplaced.join(", "),
+        diagnosis.flags().join(", "),
+    ]
+}
+
+/// A table of every rucksack, then how many are not as the puzzle describes them.
+pub fn render(diagnoses: &[Diagnosis]) -> String {
+    let rows = diagnoses.iter().map(fields).collect::<Vec<_>>();
+    let mut widths = HEADER.map(|header| header.chars().count());
+    for row in &rows {
+        for (width, field) in widths.iter_mut().zip(row) {
+            *width = (*width).max(field.chars().count());
+        }
+    }
+
+    // Numbers right-aligned, text left-aligned.
+    let mut output = String::new();
+    let header = HEADER.map(String::from);
+    for row in std::iter::once(&header).chain(&rows) {
+        let [line, items, misplaced, flags] = row;
+        let [line_width, items_width, misplaced_width, _] = widths;
+        let row = format!(
+            "{line:>line_width$}  {items:>items_width$}  {misplaced:<misplaced_width$}  {flags}"
+        );
+        writeln!(output, "{}", row.trim_end()).unwrap();
+    }
+
+    let count = |f: fn(&Diagnosis) -> bool| diagnoses.iter().filter(|d| f(d)).count();
+    writeln!(
+        output,
+        "\nRucksacks: {}, sound: {}, empty: {}, odd length: {}",
+        diagnoses.len(),
+        count(Diagnosis::is_sound),
+        count(Diagnosis::is_empty),
+        count(Diagnosis::is_odd)
+    )
+    .unwrap();
+    writeln!(
+        output,
+        "Without misplaced items: {}, with several: {}",
+        count(|d| !d.is_empty() && d.misplaced.is_empty()),
+        count(|d| d.misplaced.len() > 1)
+    )
+    .unwrap();
+    let priorities = diagnoses
+        .iter()
+        .flat_map(|d| &d.misplaced)
+        .map(|m| m.item.priority())
+        .sum::<u32>();
+    writeln!(output, "Priority sum of misplaced items: {priorities}").unwrap();
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{items::Letters, Day03};
+    use aoc_common::Solution;
+
+    #[test]
+    fn diagnostics() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\n\nabcab\naAbB\naaXbaY";
+        let diagnoses = diagnose(input, &Letters).unwrap();
+
+        let first = &diagnoses[0];
+        assert_eq!((first.line, first.items), (1, 24));
+        assert_eq!(
+            first.misplaced,
+            [Misplaced {
+                item: Item::new('p').unwrap(),
+                left: 1,
+                right: 1
+            }]
+        );
+        assert!(first.is_sound());
+        assert!(diagnoses[1].is_empty() && diagnoses[2].is_odd());
+        assert_eq!(diagnoses[4].misplaced[0].left, 2);
+
+        let expected = "\
+line  items  misplaced (left/right)  flags
+   1     24  p 1/1
+   2      0                          empty
+   3      5  a 1/1, b 1/1            odd length, 2 misplaced items
+   4      4                          no misplaced item
+   5      6  a 2/1
+
+Rucksacks: 5, sound: 2, empty: 1, odd length: 1
+Without misplaced items: 1, with several: 1
+Priority sum of misplaced items: 20
+";
+        assert_eq!(render(&diagnoses), expected);
+
+        let e = diagnose("ab\nab1b", &Letters).unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 3));
+    }
+
+    #[test]
+    fn agrees_with_part_one() {
+        let input = include_str!("input_data/test.txt");
+        let diagnoses = diagnose(input, &Letters).unwrap();
+        assert!(diagnoses.iter().all(Diagnosis::is_sound));
+
+        let priorities = diagnoses
+            .iter()
+            .map(|d| d.misplaced[0].item.priority())
+            .sum::<u32>();
+        assert_eq!(priorities, Day03::part_one(&Day03::parse(input).unwrap()));
+    }
+}
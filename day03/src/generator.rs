@@ -0,0 +1,56 @@
+use crate::Day03;
+use aoc_common::{rng::Rng, Generate};
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// A compartment of `len` items drawn from `pool`, holding every item of `required`.
+fn compartment(rng: &mut Rng, len: usize, required: &[u8], pool: &[u8]) -> Vec<u8> {
+    let mut items = required.to_vec();
+    while items.len() < len {
+        items.push(*rng.choose(pool));
+    }
+    rng.shuffle(&mut items);
+    items
+}
+
+impl Generate for Day03 {
+    const SIZE: usize = 300;
+
+    // `size` rucksacks rounded up to whole groups of three. Each group only has its badge in
+    // common, and the compartments of each rucksack only one misplaced item.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut sacks = vec![];
+
+        for _ in 0..size.max(1).div_ceil(3) {
+            let mut items = ITEMS.to_vec();
+            rng.shuffle(&mut items);
+            let (badge, others) = items.split_first().unwrap();
+
+            // Each rucksack draws its other items from its own 17 types.
+            for pool in others.chunks(17) {
+                let (misplaced, pool) = pool.split_first().unwrap();
+                let (left, right) = pool.split_at(pool.len() / 2);
+                let len = rng.range(4..=16);
+
+                let (mut left_required, mut right_required) = (vec![*misplaced], vec![*misplaced]);
+                if rng.chance(0.5) {
+                    left_required.push(*badge);
+                } else {
+                    right_required.push(*badge);
+                }
+
+                let mut sack =
+                    compartment(rng, len, &left_required, &[left, &[*misplaced]].concat());
+                sack.extend(compartment(
+                    rng,
+                    len,
+                    &right_required,
+                    &[right, &[*misplaced]].concat(),
+                ));
+                sacks.push(String::from_utf8(sack).unwrap());
+            }
+        }
+
+        sacks.join("\n")
+    }
+}
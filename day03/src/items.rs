@@ -0,0 +1,229 @@
+use std::fmt;
+
+/// How items are written and ranked. Priorities go from 1 to 63, to fit in an [`ItemSet`].
+pub trait PriorityScheme {
+    /// Priority of the item written `symbol`, if it is one.
+    fn priority(&self, symbol: char) -> Option<u32>;
+}
+
+/// The puzzle's items: a to z with priorities 1 to 26, A to Z with 27 to 52.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Letters;
+
+impl PriorityScheme for Letters {
+    fn priority(&self, symbol: char) -> Option<u32> {
+        match symbol {
+            'a'..='z' => Some(symbol as u32 - 'a' as u32 + 1),
+            'A'..='Z' => Some(symbol as u32 - 'A' as u32 + 27),
+            _ => None,
+        }
+    }
+}
+
+/// Items written with the symbols of an alphabet, ranked in its order from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Alphabet {
+    symbols: Vec<char>,
+}
+
+impl Alphabet {
+    /// Alphabet of the distinct characters of `symbols`, 63 at most.
+    pub fn new(symbols: &str) -> Result<Self, String> {
+        let symbols = symbols.chars().collect::<Vec<_>>();
+        if symbols.len() > 63 {
+            return Err(format!("{} symbols, expected 63 at most", symbols.len()));
+        }
+        if let Some(i) = (1..symbols.len()).find(|&i| symbols[..i].contains(&symbols[i])) {
+            return Err(format!("symbol `{}` given twice", symbols[i]));
+        }
+        Ok(Self { symbols })
+    }
+
+    pub fn symbols(&self) -> &[char] {
+        &self.symbols
+    }
+}
+
+impl PriorityScheme for Alphabet {
+    fn priority(&self, symbol: char) -> Option<u32> {
+        let position = self.symbols.iter().position(|&s| s == symbol)?;
+        Some(position as u32 + 1)
+    }
+}
+
+/// A character that is not an item of the priority scheme in use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ItemError {
+    pub symbol: char,
+}
+
+impl fmt::Display for ItemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not an item", self.symbol)
+    }
+}
+
+impl std::error::Error for ItemError {}
+
+/// An item, with its priority in the scheme it was read with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Item {
+    symbol: char,
+    priority: u32,
+}
+
+impl Item {
+    /// The puzzle's item written `symbol`.
+    pub fn new(symbol: char) -> Result<Self, ItemError> {
+        Self::with_scheme(symbol, &Letters)
+    }
+
+    pub fn with_scheme(symbol: char, scheme: &impl PriorityScheme) -> Result<Self, ItemError> {
+        match scheme.priority(symbol) {
+            Some(priority @ 1..=63) => Ok(Self { symbol, priority }),
+            _ => Err(ItemError { symbol }),
+        }
+    }
+
+    pub fn symbol(self) -> char {
+        self.symbol
+    }
+
+    pub fn priority(self) -> u32 {
+        self.priority
+    }
+}
+
+/// A set of items, by priority: bit `p` of the mask stands for the item of priority `p`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: Self = Self(0);
+
+    /// The puzzle's items written in `bytes`, none if one of them is not an item.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.iter().try_fold(Self::EMPTY, |mut set, &byte| {
+            set.insert(Item::new(byte.into()).ok()?);
+            Some(set)
+        })
+    }
+
+    pub fn insert(&mut self, item: Item) {
+        self.0 |= 1 << item.priority;
+    }
+
+    pub fn contains(self, priority: u32) -> bool {
+        (1..=63).contains(&priority) && self.0 & 1 << priority != 0
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Priorities of the items, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=63).filter(move |&priority| self.contains(priority))
+    }
+
+    /// Sum of the priorities of the items.
+    pub fn priority_sum(self) -> u32 {
+        self.priorities().sum()
+    }
+}
+
+impl FromIterator<Item> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = Item>>(items: I) -> Self {
+        let mut set = Self::EMPTY;
+        for item in items {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priorities() {
+        let priority = |symbol| Item::new(symbol).map(Item::priority);
+        assert_eq!(priority('a'), Ok(1));
+        assert_eq!(priority('z'), Ok(26));
+        assert_eq!(priority('A'), Ok(27));
+        assert_eq!(priority('Z'), Ok(52));
+        for symbol in ['[', '`', '0', ' ', 'é'] {
+            assert_eq!(priority(symbol), Err(ItemError { symbol }));
+        }
+        assert_eq!(ItemError { symbol: '1' }.to_string(), "`1` is not an item");
+
+        let set = ItemSet::from_bytes(b"vJrwpWtwJgWr").unwrap();
+        assert_eq!(set.len(), 8);
+        assert_eq!(
+            set.priorities().collect::<Vec<_>>(),
+            [7, 16, 18, 20, 22, 23, 36, 49]
+        );
+        assert!(set.contains(23) && !set.contains(1) && !set.contains(0));
+        assert_eq!(ItemSet::from_bytes(b""), Some(ItemSet::EMPTY));
+        assert_eq!(ItemSet::from_bytes(b"ab1"), None);
+    }
+
+    #[test]
+    fn operations() {
+        let [a, b] = [b"abcZ", b"bcdZ"].map(|bytes| ItemSet::from_bytes(bytes).unwrap());
+        assert_eq!(a.intersection(b), ItemSet::from_bytes(b"bcZ").unwrap());
+        assert_eq!(a.union(b), ItemSet::from_bytes(b"abcdZ").unwrap());
+        assert_eq!(a.difference(b), ItemSet::from_bytes(b"a").unwrap());
+        assert_eq!(a.intersection(b).priority_sum(), 2 + 3 + 52);
+        assert!(a.difference(a).is_empty());
+    }
+
+    #[test]
+    fn schemes() {
+        let digits = Alphabet::new("0123456789").unwrap();
+        let item = Item::with_scheme('3', &digits).unwrap();
+        assert_eq!((item.symbol(), item.priority()), ('3', 4));
+        assert!(Item::with_scheme('a', &digits).is_err());
+
+        let set = "90€"
+            .chars()
+            .map(|symbol| Item::with_scheme(symbol, &digits));
+        assert_eq!(
+            set.clone()
+                .take(2)
+                .collect::<Result<ItemSet, _>>()
+                .unwrap()
+                .priority_sum(),
+            11
+        );
+        assert_eq!(
+            set.collect::<Result<ItemSet, _>>(),
+            Err(ItemError { symbol: '€' })
+        );
+
+        let full = (0..63)
+            .map(|i| char::from_u32(0x100 + i).unwrap())
+            .collect::<String>();
+        let last = full.chars().last().unwrap();
+        assert_eq!(Alphabet::new(&full).unwrap().priority(last), Some(63));
+        assert!(Alphabet::new(&format!("{full}!")).is_err());
+        assert!(Alphabet::new("abca").is_err());
+    }
+}
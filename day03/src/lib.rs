@@ -0,0 +1,303 @@
+pub mod diagnostics;
+mod generator;
+pub mod items;
+
+use aoc_common::{parse_lines, ParseError, Solution};
+use items::{Item, ItemSet, Letters, PriorityScheme};
+use std::{fmt, num::NonZeroUsize};
+
+/// The items of the two compartments of a rucksack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    pub left: ItemSet,
+    pub right: ItemSet,
+}
+
+impl Rucksack {
+    pub fn items(&self) -> ItemSet {
+        self.left.union(self.right)
+    }
+
+    /// Items in both compartments, a single one in the puzzle.
+    pub fn misplaced(&self) -> ItemSet {
+        self.left.intersection(self.right)
+    }
+}
+
+/// Rucksacks of a group, from `first` on, and the items they all hold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Group {
+    pub first: usize,
+    pub badges: ItemSet,
+}
+
+/// Why rucksacks do not make groups with a single badge each. Rucksacks are numbered from 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupError {
+    NoBadge {
+        first: usize,
+    },
+    SeveralBadges {
+        first: usize,
+        badges: ItemSet,
+    },
+    /// Rucksacks at the end too few to make a group.
+    Leftover {
+        first: usize,
+        count: usize,
+    },
+}
+
+impl fmt::Display for GroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoBadge { first } => {
+                write!(f, "group from rucksack {} has no badge", first + 1)
+            }
+            Self::SeveralBadges { first, badges } => write!(
+                f,
+                "group from rucksack {} has {} badges",
+                first + 1,
+                badges.len()
+            ),
+            Self::Leftover { first, count } => {
+                write!(f, "{count} rucksacks left over from rucksack {}", first + 1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GroupError {}
+
+/// Rucksacks split into groups of a given size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Groups {
+    pub groups: Vec<Group>,
+    /// Rucksacks at the end, fewer than a group.
+    pub leftover: usize,
+    /// Index of the first of them.
+    pub leftover_from: usize,
+}
+
+impl Groups {
+    /// Groups of `size` rucksacks, in order.
+    pub fn new(sacks: &[Rucksack], size: NonZeroUsize) -> Self {
+        let size = size.get();
+        let chunks = sacks.chunks_exact(size);
+        let leftover = chunks.remainder().len();
+        let groups = chunks
+            .enumerate()
+            .map(|(i, group)| Group {
+                first: i * size,
+                badges: group
+                    .iter()
+                    .map(Rucksack::items)
+                    .reduce(ItemSet::intersection)
+                    .unwrap(),
+            })
+            .collect();
+        Self {
+            groups,
+            leftover,
+            leftover_from: sacks.len() - leftover,
+        }
+    }
+
+    /// Groups without exactly one badge, then the rucksacks left over.
+    pub fn errors(&self) -> Vec<GroupError> {
+        let mut errors = self
+            .groups
+            .iter()
+            .filter_map(|&Group { first, badges }| match badges.len() {
+                0 => Some(GroupError::NoBadge { first }),
+                1 => None,
+                _ => Some(GroupError::SeveralBadges { first, badges }),
+            })
+            .collect::<Vec<_>>();
+        if self.leftover > 0 {
+            errors.push(GroupError::Leftover {
+                first: self.leftover_from,
+                count: self.leftover,
+            });
+        }
+        errors
+    }
+}
+
+// Items of a rucksack, written in `scheme`.
+pub(crate) fn read_items<'a>(
+    line: &'a str,
+    scheme: &'a impl PriorityScheme,
+) -> impl Iterator<Item = Result<Item, ParseError>> + 'a {
+    line.char_indices().map(|(i, symbol)| {
+        Item::with_scheme(symbol, scheme)
+            .map_err(|e| ParseError::at(line, &line[i..i + symbol.len_utf8()], e.to_string()))
+    })
+}
+
+/// Reads one rucksack per line, its items written in `scheme`.
+pub fn parse_with(input: &str, scheme: &impl PriorityScheme) -> Result<Vec<Rucksack>, ParseError> {
+    parse_lines(input, |line| {
+        let count = line.chars().count();
+        let mut sack = Rucksack {
+            left: ItemSet::EMPTY,
+            right: ItemSet::EMPTY,
+        };
+        for (i, item) in read_items(line, scheme).enumerate() {
+            let compartment = if i < count / 2 {
+                &mut sack.left
+            } else {
+                &mut sack.right
+            };
+            compartment.insert(item?);
+        }
+
+        if !count.is_multiple_of(2) {
+            return Err(ParseError::new(line, "odd number of items"));
+        }
+        Ok(sack)
+    })
+}
+
+pub struct Day03;
+
+// Elves of a group in the puzzle.
+const ELVES_PER_GROUP: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Rucksack>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_with(input, &Letters)
+    }
+
+    fn part_one(sacks: &Self::Input<'_>) -> Self::PartOne {
+        sacks
+            .iter()
+            .map(|sack| sack.misplaced().priority_sum())
+            .sum()
+    }
+
+    fn part_two(sacks: &Self::Input<'_>) -> Self::PartTwo {
+        Groups::new(sacks, ELVES_PER_GROUP)
+            .groups
+            .iter()
+            .map(|group| group.badges.priority_sum())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{rng::Rng, Generate};
+
+    #[test]
+    fn day03_a() {
+        let input = Day03::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day03::part_one(&input), 157);
+    }
+
+    #[test]
+    fn day03_b() {
+        let input = Day03::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day03::part_two(&input), 70);
+    }
+
+    #[test]
+    fn day03_invalid() {
+        let e = Day03::parse("abca\nab-cd").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 3));
+        assert_eq!(e.reason(), "`-` is not an item");
+
+        let e = Day03::parse("aébc").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(1), 2));
+
+        let e = Day03::parse("abc").unwrap_err();
+        assert_eq!(e.line(), Some(1));
+    }
+
+    fn size(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn day03_groups() {
+        let sacks = Day03::parse(include_str!("input_data/test.txt")).unwrap();
+        let groups = Groups::new(&sacks, size(3));
+        let badges = groups.groups.iter().map(|group| group.badges);
+        assert_eq!(
+            badges.collect::<Vec<_>>(),
+            [b"r", b"Z"].map(|badge| ItemSet::from_bytes(badge).unwrap())
+        );
+        assert_eq!(groups.errors(), []);
+
+        let groups = Groups::new(&sacks, size(4));
+        assert_eq!(groups.groups.len(), 1);
+        assert_eq!(
+            groups.errors(),
+            [
+                GroupError::NoBadge { first: 0 },
+                GroupError::Leftover { first: 4, count: 2 }
+            ]
+        );
+
+        assert_eq!(
+            groups.errors()[0].to_string(),
+            "group from rucksack 1 has no badge"
+        );
+
+        let errors = Groups::new(&sacks, size(2)).errors();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(
+            errors[2],
+            GroupError::SeveralBadges {
+                first: 4,
+                badges: ItemSet::from_bytes(b"GJZ").unwrap()
+            }
+        );
+        assert_eq!(errors[2].to_string(), "group from rucksack 5 has 3 badges");
+
+        assert_eq!(
+            Groups::new(&sacks, size(1)).groups[5].badges,
+            sacks[5].items()
+        );
+        assert!(Groups::new(&[], size(3)).groups.is_empty());
+    }
+
+    #[test]
+    fn day03_schemes() {
+        // The example with digits for the lowercase letters, and the same priorities.
+        let alphabet =
+            items::Alphabet::new("0123456789!\"#$%&'()*+,-./:ABCDEFGHIJKLMNOPQRSTUVWXYZ").unwrap();
+        let example = include_str!("input_data/test.txt")
+            .chars()
+            .map(|c| match c {
+                'a'..='z' => alphabet.symbols()[c as usize - 'a' as usize],
+                c => c,
+            })
+            .collect::<String>();
+
+        let sacks = parse_with(&example, &alphabet).unwrap();
+        assert_eq!(
+            (Day03::part_one(&sacks), Day03::part_two(&sacks)),
+            (157, 70)
+        );
+        assert!(Day03::parse(&example).is_err());
+    }
+
+    #[test]
+    fn day03_generated() {
+        for seed in 0..10 {
+            let input = Day03::generate(&mut Rng::new(seed), 100);
+            let sacks = Day03::parse(&input).unwrap();
+            assert_eq!(sacks.len(), 102);
+            assert!(sacks.iter().all(|sack| sack.misplaced().len() == 1));
+            assert_eq!(Groups::new(&sacks, ELVES_PER_GROUP).errors(), []);
+        }
+    }
+}
@@ -1,68 +1,52 @@
-#![feature(iter_array_chunks)]
-
-use array_tool::vec::Intersect;
-use tuple::Map;
-
-// Only dealing with ASCII stuff, luckily, otherwise this would be completely wrong.
-fn score(input: impl Iterator<Item = char>) -> impl Iterator<Item = u32> {
-    input
-        // Invert character case.
-        .map(|c| {
-            if c.is_lowercase() {
-                c.to_uppercase().next()
-            } else {
-                c.to_lowercase().next()
-            }
-            .unwrap()
-        })
-        // Map characters to their unicode codepoint and remove offset to go on range [1..].
-        .map(|a| a as u32 - 'A' as u32 + 1)
-        // Correct offset error between lowercase and upercase in unicode, 6 characters in-between [ / ] ^ _ `.
-        .map(|a| if a > 26 { a - 6 } else { a })
-}
-
-fn solve_a(input: &str) -> u32 {
-    let pockets = input.lines().map(|sack| {
-        sack.split_at(sack.len() / 2)
-            .map(|pocket| pocket.chars().collect::<Vec<char>>())
-    });
-
-    let mismatches = pockets.map(|(a, b)| *a.intersect(b).first().unwrap());
-
-    score(mismatches).sum()
-}
-
-fn solve_b(input: &str) -> u32 {
-    let sacks = input
-        .lines()
-        .map(|sack| sack.chars().collect::<Vec<char>>());
-
-    let badges = sacks
-        .array_chunks::<3>()
-        .map(|[a, b, c]| *a.intersect(b).intersect(c).first().unwrap());
-
-    score(badges).sum()
-}
-
-fn main() {
-    let input = include_str!("input_data/input.txt");
-    println!("Part 1: {}", solve_a(input));
-    println!("Part 2: {}", solve_b(input));
+use aoc_common::{
+    input::{self, InputSource},
+    Solution,
+};
+use day03::{diagnostics, items::Letters, Day03};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: day03 [INPUT] [--diagnose]
+
+Without options, prints the answers of both parts. `--diagnose` prints every rucksack with the
+items found in both of its compartments, counted in each, and flags empty rucksacks, odd
+lengths and rucksacks without exactly one misplaced item, then a summary.";
+
+fn parse_args() -> Result<Option<(Option<InputSource>, bool)>, String> {
+    let (mut source, mut diagnose) = (None, false);
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--diagnose" => diagnose = true,
+            _ if source.is_none() => source = Some(InputSource::from_arg(&arg)),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(Some((source, diagnose)))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn day03_a() {
-        let input = include_str!("input_data/test.txt");
-        assert_eq!(solve_a(input), 157);
+fn main() -> ExitCode {
+    let (source, diagnose) = match parse_args() {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => return aoc_common::exit(Err(format!("{e}\n\n{USAGE}"))),
+    };
+
+    let source = source.unwrap_or_else(InputSource::from_env);
+    // Without `--diagnose`, answered like every other day.
+    if !diagnose {
+        return aoc_common::main_from::<Day03>(&source);
     }
 
-    #[test]
-    fn day03_b() {
-        let input = include_str!("input_data/test.txt");
-        assert_eq!(solve_b(input), 70);
+    let input = match input::load(Day03::DAY, &source) {
+        Ok(input) => input,
+        Err(e) => return aoc_common::exit(Err(e)),
+    };
+    match diagnostics::diagnose(&input, &Letters) {
+        Ok(diagnoses) => print!("{}", diagnostics::render(&diagnoses)),
+        Err(e) => return aoc_common::exit(Err(e.in_day(Day03::DAY))),
     }
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,19 @@
+use crate::Day04;
+use aoc_common::{rng::Rng, Generate};
+
+impl Generate for Day04 {
+    const SIZE: usize = 1000;
+
+    // `size` pairs of section ranges within 1-99.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut range = || {
+            let low = rng.range(1..=99);
+            format!("{low}-{}", rng.range(low..=99))
+        };
+
+        (0..size.max(1))
+            .map(|_| format!("{},{}", range(), range()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,174 @@
+mod generator;
+
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+type Pair = (RangeInclusive<i32>, RangeInclusive<i32>);
+
+fn to_range(s: &str) -> Result<RangeInclusive<i32>, ParseError> {
+    let (low, high) = s
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(s, "expected a `low-high` range"))?;
+
+    let bound = |bound: &str| {
+        bound
+            .parse::<i32>()
+            .map_err(|e| ParseError::at(s, bound, format!("invalid bound: {e}")))
+    };
+
+    let (low, high) = (bound(low)?, bound(high)?);
+    if low > high {
+        return Err(ParseError::new(s, "range ends before it starts"));
+    }
+
+    Ok(low..=high)
+}
+
+fn count(pairs: &[Pair], filter_fn: impl FnMut(&&Pair) -> bool) -> i32 {
+    pairs.iter().filter(filter_fn).count() as i32
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Pair>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(input, |line| {
+            let (first, second) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(line, "expected two comma separated ranges"))?;
+
+            Ok((
+                to_range(first).map_err(|e| e.within(line, first))?,
+                to_range(second).map_err(|e| e.within(line, second))?,
+            ))
+        })
+    }
+
+    fn part_one(pairs: &Self::Input<'_>) -> Self::PartOne {
+        count(pairs, |(first, second)| {
+            (first.start() <= second.start() && second.end() <= first.end())
+                || (second.start() <= first.start() && first.end() <= second.end())
+        })
+    }
+
+    fn part_two(pairs: &Self::Input<'_>) -> Self::PartTwo {
+        count(pairs, |(first, second)| {
+            first.start() <= second.end() && second.start() <= first.end()
+        })
+    }
+}
+
+// Section by section checks, to compare the bound checks against.
+#[cfg(test)]
+mod reference {
+    use super::*;
+
+    pub fn part_one(pairs: &[Pair]) -> i32 {
+        count(pairs, |(first, second)| {
+            let mut first_in_second = true;
+            let mut second_in_first = true;
+
+            for i in first.clone() {
+                if !second.contains(&i) {
+                    first_in_second = false;
+                    break;
+                }
+            }
+
+            for i in second.clone() {
+                if !first.contains(&i) {
+                    second_in_first = false;
+                    break;
+                }
+            }
+
+            first_in_second || second_in_first
+        })
+    }
+
+    pub fn part_two(pairs: &[Pair]) -> i32 {
+        count(pairs, |(first, second)| {
+            let mut first_in_second = false;
+            let mut second_in_first = false;
+
+            for i in first.clone() {
+                if second.contains(&i) {
+                    first_in_second = true;
+                    break;
+                }
+            }
+
+            for i in second.clone() {
+                if first.contains(&i) {
+                    second_in_first = true;
+                    break;
+                }
+            }
+
+            first_in_second || second_in_first
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{check::check, rng::Rng, Generate};
+
+    #[test]
+    fn day04_a() {
+        let input = Day04::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day04::part_one(&input), 2);
+    }
+
+    #[test]
+    fn day04_b() {
+        let input = Day04::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day04::part_two(&input), 4);
+    }
+
+    #[test]
+    fn day04_invalid() {
+        let e = Day04::parse("2-4,6-8\n2-3,4-x").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (Some(2), 7, "2-3,4-x"));
+
+        let e = Day04::parse("2-4").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(1), 1));
+
+        let e = Day04::parse("2-4,8-6").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(1), 5));
+    }
+
+    #[test]
+    fn day04_generated() {
+        // Sections within 1-99.
+        for seed in 0..10 {
+            let input = Day04::generate(&mut Rng::new(seed), Day04::SIZE);
+            let pairs = Day04::parse(&input).unwrap();
+            assert_eq!(pairs.len(), Day04::SIZE);
+            let mut ranges = pairs.iter().flat_map(|(a, b)| [a, b]);
+            assert!(ranges.all(|range| *range.start() >= 1 && *range.end() <= 99));
+        }
+    }
+
+    #[test]
+    fn day04_reference() {
+        check::<Day04>(1..=100, |pairs| {
+            Day04::part_one(pairs) == reference::part_one(pairs)
+                && Day04::part_two(pairs) == reference::part_two(pairs)
+        });
+    }
+
+    #[test]
+    fn day04_contained_pairs_overlap() {
+        check::<Day04>(1..=1000, |pairs| {
+            Day04::part_one(pairs) <= Day04::part_two(pairs)
+        });
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Day05;
+use aoc_common::{rng::Rng, Generate};
+
+const STACKS: usize = 9;
+
+impl Generate for Day05 {
+    const SIZE: usize = 500;
+
+    // Nine stacks of crates and `size` moves, each taking crates a stack actually holds.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let stacks = (0..STACKS)
+            .map(|_| {
+                (0..rng.range(1..=8))
+                    .map(|_| rng.range(b'A'..=b'Z') as char)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+        let mut lines = (0..height)
+            .rev()
+            .map(|level| {
+                stacks
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(c) => format!("[{c}]"),
+                        None => "   ".to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        lines.push(
+            (1..=STACKS)
+                .map(|n| format!(" {n} "))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        lines.push(String::new());
+
+        let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+        for _ in 0..size {
+            let source = loop {
+                let stack = rng.range(0..=STACKS - 1);
+                if heights[stack] > 0 {
+                    break stack;
+                }
+            };
+            let target = (source + rng.range(1..=STACKS - 1)) % STACKS;
+            let count = rng.range(1..=heights[source].min(10));
+
+            heights[source] -= count;
+            heights[target] += count;
+            lines.push(format!(
+                "move {count} from {} to {}",
+                source + 1,
+                target + 1
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,264 @@
+mod generator;
+
+use aoc_common::{
+    parse::{finish, tag, unsigned},
+    ParseError, Solution,
+};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Instruction {
+    source: usize,
+    target: usize,
+    count: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        finish(s, |s| {
+            let (s, _) = tag("move ")(s)?;
+            let (s, count) = unsigned(s)?;
+            let (s, _) = tag(" from ")(s)?;
+            let (s, source) = unsigned(s)?;
+            let (s, _) = tag(" to ")(s)?;
+            let (s, target) = unsigned(s)?;
+            Ok((
+                s,
+                Instruction {
+                    source,
+                    target,
+                    count,
+                },
+            ))
+        })
+    }
+}
+
+// Reads the stacks, bottom first, from the diagram lines ending with the stack numbers.
+fn init<'a>(lines: &[&'a str]) -> Result<Vec<Vec<&'a str>>, ParseError> {
+    let (numbers, crates) = lines
+        .split_last()
+        .ok_or_else(|| ParseError::new("", "missing stack diagram").at_line(1))?;
+    let stack_count = numbers.split_whitespace().count();
+    if stack_count == 0 {
+        return Err(ParseError::new(numbers, "missing stack numbers").at_line(lines.len()));
+    }
+
+    let mut stacks = vec![vec![]; stack_count];
+
+    for (i, line) in crates.iter().enumerate().rev() {
+        let error = |start: usize, end: usize, reason: &str| {
+            let part = line.get(start..end.min(line.len())).unwrap_or(line);
+            ParseError::at(line, part, reason).at_line(i + 1)
+        };
+
+        // Each stack takes three characters, `[X]` or blanks, plus a separator.
+        for (n, stack) in stacks.iter_mut().enumerate() {
+            let start = n * 4;
+            match line.as_bytes().get(start..(start + 3).min(line.len())) {
+                None | Some(b"" | b" " | b"  " | b"   ") => {}
+                Some(&[b'[', c, b']']) if c.is_ascii_alphanumeric() || c == b'_' => {
+                    stack.push(&line[start + 1..start + 2])
+                }
+                Some(_) => return Err(error(start, start + 3, "expected a crate like `[A]`")),
+            }
+            // The separator after the last stack is checked with the rest of the line.
+            match line.as_bytes().get(start + 3) {
+                Some(&c) if c != b' ' && n + 1 < stack_count => {
+                    return Err(error(
+                        start + 3,
+                        start + 4,
+                        "expected a space between crates",
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        let end = stack_count * 4 - 1;
+        if line.len() > end && !line[end..].trim().is_empty() {
+            return Err(error(
+                end,
+                line.len(),
+                "crate outside of the numbered stacks",
+            ));
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn tops(stacks: &[Vec<&str>]) -> String {
+    stacks
+        .iter()
+        .filter_map(|stack| stack.last().copied())
+        .collect()
+}
+
+/// Starting crate stacks and the rearrangement procedure to apply to them, crates borrowed
+/// from the input.
+#[derive(Debug)]
+pub struct Procedure<'a> {
+    stacks: Vec<Vec<&'a str>>,
+    instructions: Vec<Instruction>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Procedure<'a>;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let blank = lines
+            .iter()
+            .position(|line| line.trim().is_empty())
+            .ok_or_else(|| {
+                ParseError::new(
+                    lines.last().unwrap_or(&""),
+                    "missing blank line after the stack diagram",
+                )
+                .at_line(lines.len().max(1))
+            })?;
+
+        let stacks = init(&lines[..blank])?;
+        let mut heights = stacks.iter().map(|stack| stack.len()).collect::<Vec<_>>();
+        let mut instructions = vec![];
+
+        for (i, line) in lines.iter().enumerate().skip(blank + 1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let error = |reason: String| ParseError::new(line, reason).at_line(i + 1);
+            let instruction = line.parse::<Instruction>().map_err(|e| e.at_line(i + 1))?;
+
+            // Check the moves against the stack heights, so that solving cannot fail.
+            for stack in [instruction.source, instruction.target] {
+                if stack == 0 || stack > heights.len() {
+                    return Err(error(format!(
+                        "no stack {stack}, expected 1 to {}",
+                        heights.len()
+                    )));
+                }
+            }
+            if heights[instruction.source - 1] < instruction.count {
+                return Err(error(format!(
+                    "cannot move {} crates from stack {} holding {}",
+                    instruction.count,
+                    instruction.source,
+                    heights[instruction.source - 1]
+                )));
+            }
+            heights[instruction.source - 1] -= instruction.count;
+            heights[instruction.target - 1] += instruction.count;
+
+            instructions.push(instruction);
+        }
+
+        Ok(Procedure {
+            stacks,
+            instructions,
+        })
+    }
+
+    fn part_one(procedure: &Self::Input<'_>) -> Self::PartOne {
+        let mut stacks = procedure.stacks.clone();
+
+        for instruction in &procedure.instructions {
+            for _ in 0..instruction.count {
+                let item = stacks[instruction.source - 1].pop().unwrap();
+                stacks[instruction.target - 1].push(item);
+            }
+        }
+
+        tops(&stacks)
+    }
+
+    fn part_two(procedure: &Self::Input<'_>) -> Self::PartTwo {
+        let mut stacks = procedure.stacks.clone();
+
+        for instruction in &procedure.instructions {
+            // Pop multiple at once!
+            let source = &mut stacks[instruction.source - 1];
+            let mut items = source.split_off(source.len() - instruction.count);
+            stacks[instruction.target - 1].append(&mut items);
+        }
+
+        tops(&stacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{rng::Rng, Generate};
+
+    #[test]
+    fn day05_a() {
+        let input = Day05::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day05::part_one(&input), "CMZ".to_string());
+    }
+
+    #[test]
+    fn day05_b() {
+        let input = Day05::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day05::part_two(&input), "MCD".to_string());
+    }
+
+    #[test]
+    fn day05_invalid() {
+        let e =
+            Day05::parse("[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmove x from 1 to 2").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(5), 6));
+
+        let e = Day05::parse("[A] [B]\n 1   2\n\nmove 1 form 1 to 2").unwrap_err();
+        assert_eq!((e.column(), e.reason()), (7, "expected ` from `"));
+
+        let e = Day05::parse("[A] {B}\n 1   2\n\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (Some(1), 5, "[A] {B}"));
+
+        let e = Day05::parse("[A]x[B]\n 1   2\n\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!(
+            (e.line(), e.column(), e.reason()),
+            (Some(1), 4, "expected a space between crates")
+        );
+
+        let e =
+            Day05::parse("[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!(
+            (e.line(), e.reason()),
+            (Some(5), "cannot move 1 crates from stack 1 holding 0")
+        );
+
+        let e = Day05::parse("[A] [B]\n 1   2\n\nmove 1 from 3 to 2").unwrap_err();
+        assert_eq!(e.line(), Some(4));
+
+        assert!(Day05::parse("move 1 from 1 to 2").is_err());
+    }
+
+    #[test]
+    fn day05_generated() {
+        for seed in 0..10 {
+            let input = Day05::generate(&mut Rng::new(seed), Day05::SIZE);
+            let procedure = Day05::parse(&input).unwrap();
+            assert_eq!(procedure.instructions.len(), Day05::SIZE);
+
+            // Nine stacks of 1 to 8 crates, moved by 10 at most.
+            assert_eq!(procedure.stacks.len(), 9);
+            assert!(procedure
+                .stacks
+                .iter()
+                .all(|stack| (1..=8).contains(&stack.len())));
+            assert!(procedure
+                .instructions
+                .iter()
+                .all(|instruction| (1..=10).contains(&instruction.count)));
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::Day06;
+use aoc_common::{rng::Rng, Generate};
+
+impl Generate for Day06 {
+    const SIZE: usize = 4096;
+
+    // A datastream of `size` characters whose markers only come up in its last 20 characters,
+    // the ones before being drawn from three letters only.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut letters = (b'a'..=b'z').collect::<Vec<_>>();
+        rng.shuffle(&mut letters);
+        let (marker, filler) = letters.split_at(14);
+
+        let mut datastream = (0..size.saturating_sub(20))
+            .map(|_| *rng.choose(&filler[..3]))
+            .collect::<Vec<_>>();
+        datastream.extend(marker);
+        datastream.extend((0..6).map(|_| *rng.choose(&letters)));
+
+        String::from_utf8(datastream).unwrap()
+    }
+}
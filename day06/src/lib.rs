@@ -0,0 +1,213 @@
+mod generator;
+
+use aoc_common::{stream, stream::StreamError, ParseError, Solution, Stream};
+use std::{collections::HashMap, io::BufRead};
+
+// Looks for the first `WIN_SIZE` distinct characters, one character at a time. Moves the window
+// start past the previous occurrence of each character read.
+struct Marker<const WIN_SIZE: usize> {
+    last_seen: HashMap<char, usize>,
+    start: usize,
+    len: usize,
+    found: Option<usize>,
+}
+
+impl<const WIN_SIZE: usize> Marker<WIN_SIZE> {
+    fn new() -> Self {
+        Self {
+            last_seen: HashMap::new(),
+            start: 0,
+            len: 0,
+            found: None,
+        }
+    }
+
+    // Reads the next character, telling whether the marker was found.
+    fn push(&mut self, c: char) -> bool {
+        if self.found.is_none() {
+            if let Some(previous) = self.last_seen.insert(c, self.len) {
+                self.start = self.start.max(previous + 1);
+            }
+            self.len += 1;
+            if self.len - self.start == WIN_SIZE {
+                self.found = Some(self.len);
+            }
+        }
+        self.found.is_some()
+    }
+
+    // Position right after the marker, or past the end if there is none.
+    fn position(&self) -> usize {
+        self.found.unwrap_or(self.len.max(WIN_SIZE - 1) + 1)
+    }
+}
+
+fn solve<const WIN_SIZE: usize>(input: &str) -> usize {
+    let mut marker = Marker::<WIN_SIZE>::new();
+    for c in input.chars() {
+        if marker.push(c) {
+            break;
+        }
+    }
+    marker.position()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = &'a str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.trim_end())
+    }
+
+    fn part_one(datastream: &Self::Input<'_>) -> Self::PartOne {
+        solve::<4>(datastream)
+    }
+
+    fn part_two(datastream: &Self::Input<'_>) -> Self::PartTwo {
+        solve::<14>(datastream)
+    }
+}
+
+// Reads characters until both markers are found. Whitespace is held back until something
+// follows it, as `parse` trims it off the end.
+impl Stream for Day06 {
+    fn solve_reader(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), StreamError> {
+        let mut packet = Marker::<4>::new();
+        let mut message = Marker::<14>::new();
+        let mut whitespace = vec![];
+
+        stream::chars(reader, |c| {
+            if c.is_whitespace() {
+                whitespace.push(c);
+                return true;
+            }
+
+            let mut found = false;
+            for c in whitespace.drain(..).chain([c]) {
+                found = packet.push(c) & message.push(c);
+            }
+            !found
+        })?;
+
+        Ok((packet.position(), message.position()))
+    }
+}
+
+// Checks every window, to compare the single pass against.
+#[cfg(test)]
+mod reference {
+    use std::collections::HashSet;
+
+    pub fn solve<const WIN_SIZE: usize>(input: &str) -> usize {
+        let characters = input.chars().collect::<Vec<_>>();
+        let unique_counts = characters
+            .windows(WIN_SIZE)
+            .map(|window| -> HashSet<_> { HashSet::from_iter(window) })
+            .map(|set| set.len());
+
+        unique_counts.take_while(|c| c < &WIN_SIZE).count() + WIN_SIZE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{
+        check::{check, check_with},
+        rng::Rng,
+        Generate,
+    };
+    use std::io::{self, BufReader, Read};
+
+    #[test]
+    fn day06_a() {
+        let input = include_str!("input_data/test.txt");
+        let processed = input
+            .lines()
+            .map(|msg| Day06::part_one(&Day06::parse(msg).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(processed, vec![7, 5, 6, 10, 11]);
+    }
+
+    #[test]
+    fn day06_b() {
+        let input = include_str!("input_data/test.txt");
+        let processed = input
+            .lines()
+            .map(|msg| Day06::part_two(&Day06::parse(msg).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(processed, vec![19, 23, 23, 29, 26]);
+    }
+
+    #[test]
+    fn day06_generated() {
+        for seed in 0..10 {
+            let input = Day06::generate(&mut Rng::new(seed), Day06::SIZE);
+            let datastream = Day06::parse(&input).unwrap();
+            assert!(Day06::part_one(&datastream) > Day06::SIZE - 20);
+            assert!(Day06::part_two(&datastream) <= Day06::SIZE);
+        }
+    }
+
+    #[test]
+    fn day06_reference() {
+        // Few distinct letters, so that markers show up anywhere, if at all.
+        let datastream = |rng: &mut Rng, size| {
+            let letters = rng.range(1..=16);
+            (0..size)
+                .map(|_| rng.range(b'a'..=b'a' + letters) as char)
+                .collect::<String>()
+        };
+
+        check_with::<Day06>(datastream, 0..=200, |datastream| {
+            Day06::part_one(datastream) == reference::solve::<4>(datastream)
+                && Day06::part_two(datastream) == reference::solve::<14>(datastream)
+        });
+    }
+
+    // A stream failing as soon as it is read.
+    struct Broken;
+
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("read past the markers"))
+        }
+    }
+
+    #[test]
+    fn day06_stream() {
+        // The first example in Greek letters, two bytes each, read a byte at a time.
+        let example = include_str!("input_data/test.txt").lines().next().unwrap();
+        let greek = example
+            .chars()
+            .map(|c| char::from_u32('α' as u32 + c as u32 - 'a' as u32).unwrap())
+            .collect::<String>();
+        let reader = BufReader::with_capacity(1, greek.as_bytes());
+        assert_eq!(Day06::solve_reader(reader).unwrap(), (7, 19));
+
+        // Nothing is read past the message marker.
+        let reader = BufReader::new(example.as_bytes().chain(Broken));
+        assert_eq!(Day06::solve_reader(reader).unwrap(), (7, 19));
+        assert!(Day06::solve_reader(BufReader::new(b"abc".chain(Broken))).is_err());
+
+        // Whitespace at the end is not part of the datastream.
+        let datastream = Day06::parse("ab c\t\n").unwrap();
+        assert_eq!(
+            Day06::solve_reader("ab c\t\n".as_bytes()).unwrap(),
+            (Day06::part_one(&datastream), Day06::part_two(&datastream))
+        );
+    }
+
+    #[test]
+    fn day06_packet_before_message() {
+        check::<Day06>(20..=5000, |datastream| {
+            Day06::part_one(datastream) <= Day06::part_two(datastream)
+        });
+    }
+}
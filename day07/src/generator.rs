@@ -0,0 +1,76 @@
+use crate::Day07;
+use aoc_common::{rng::Rng, Generate};
+
+const NAMES: &[&str] = &[
+    "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "bfqzjjct", "cgs", "dpbwg", "hbjmndt",
+    "lqgfrt", "mjf", "nzmp", "pbwqlbj", "qfb", "rvsgz", "wfmh", "zbmqtfr",
+];
+const EXTENSIONS: &[&str] = &["", ".txt", ".dat", ".lst", ".log", ".bin"];
+
+#[derive(Default)]
+struct Directory {
+    children: Vec<usize>,
+    files: Vec<u32>,
+}
+
+// Lists `dir`, then walks down each of its subdirectories.
+fn browse(dirs: &[Directory], dir: usize, rng: &mut Rng, lines: &mut Vec<String>) {
+    lines.push("$ ls".to_string());
+    let mut entries = dirs[dir]
+        .children
+        .iter()
+        .enumerate()
+        .map(|(i, _)| format!("dir {}{i}", rng.choose(NAMES)))
+        .collect::<Vec<_>>();
+    let names = entries.clone();
+    entries.extend(
+        dirs[dir]
+            .files
+            .iter()
+            .enumerate()
+            // Numbered after the subdirectories, so that no file has the name of one.
+            .map(|(i, size)| {
+                let i = i + names.len();
+                format!("{size} {}{i}{}", rng.choose(NAMES), rng.choose(EXTENSIONS))
+            }),
+    );
+    rng.shuffle(&mut entries);
+    lines.extend(entries);
+
+    for (&child, name) in dirs[dir].children.iter().zip(names) {
+        lines.push(format!("$ cd {}", &name["dir ".len()..]));
+        browse(dirs, child, rng, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+impl Generate for Day07 {
+    const SIZE: usize = 300;
+
+    // A terminal session browsing a tree of `size` files, using between 45 and 65 million of
+    // the 70 million the disk holds, so that some directory has to be deleted.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut dirs = (0..size / 4 + 1)
+            .map(|_| Directory::default())
+            .collect::<Vec<_>>();
+        for dir in 1..dirs.len() {
+            let parent = rng.range(0..=dir - 1);
+            dirs[parent].children.push(dir);
+        }
+
+        let weights = (0..size)
+            .map(|_| rng.range(1..=1000_u64))
+            .collect::<Vec<_>>();
+        let total = rng.range(45_000_000..=65_000_000_u64);
+        let sum = weights.iter().sum::<u64>();
+        for weight in weights {
+            let dir = rng.range(0..=dirs.len() - 1);
+            dirs[dir].files.push((total * weight / sum).max(1) as u32);
+        }
+
+        let mut lines = vec!["$ cd /".to_string()];
+        browse(&dirs, 0, rng, &mut lines);
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,206 @@
+mod generator;
+
+use aoc_common::{
+    parse::{finish, map, preceded, space, tag, take_while1, unsigned, word, Failure},
+    ParseError, Solution,
+};
+
+// A line of the terminal session, names borrowed from the input.
+enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(u32),
+}
+
+impl<'a> Line<'a> {
+    fn parse(s: &'a str) -> Result<Self, ParseError> {
+        let name = take_while1("a name", |_| true);
+
+        finish(s, |s| {
+            if let Ok((s, _)) = tag("$ ")(s) {
+                let (s, command) = word(s)?;
+                match command {
+                    "cd" => map(preceded(space, &name), Line::Cd)(s),
+                    "ls" => Ok((s, Line::Ls)),
+                    _ => Err(Failure::new(
+                        command,
+                        format!("unknown command `{command}`"),
+                    )),
+                }
+            } else if let Ok((s, _)) = tag("dir ")(s) {
+                map(&name, Line::Dir)(s)
+            } else {
+                let (s, size) = unsigned(s)?;
+                let (s, _) = space(s)?;
+                let (s, _) = name(s)?;
+                Ok((s, Line::File(size)))
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Directory<'a> {
+    name: &'a str,
+    directories: Vec<Directory<'a>>,
+    files: Vec<File>,
+}
+
+impl Directory<'_> {
+    // Pushes the total size of this directory then of every directory under it, returning
+    // the first. In u64, as the files of a directory can add up to more than a u32.
+    fn record_sizes(&self, sizes: &mut Vec<u64>) -> u64 {
+        let index = sizes.len();
+        sizes.push(0);
+
+        let dir_size: u64 = self
+            .directories
+            .iter()
+            .map(|dir| dir.record_sizes(sizes))
+            .sum();
+        let file_size: u64 = self.files.iter().map(|file| u64::from(file.size)).sum();
+        sizes[index] = dir_size + file_size;
+        sizes[index]
+    }
+}
+
+#[derive(Debug)]
+struct File {
+    size: u32,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    // Total size of every directory in the tree, root first.
+    type Input<'a> = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut root_dir = Directory {
+            name: "/",
+            directories: vec![],
+            files: vec![],
+        };
+        // Position of every directory of the path in its parent.
+        let mut path: Vec<usize> = vec![];
+
+        for (i, text) in input.lines().enumerate() {
+            let line = Line::parse(text).map_err(|e| e.at_line(i + 1))?;
+
+            let mut working_dir = &mut root_dir;
+            for &index in &path {
+                working_dir = &mut working_dir.directories[index];
+            }
+
+            match line {
+                Line::Cd("/") => path.clear(),
+                Line::Cd("..") => {
+                    path.pop();
+                }
+                Line::Cd(name) => {
+                    let index = working_dir
+                        .directories
+                        .iter()
+                        .position(|d| d.name == name)
+                        .ok_or_else(|| {
+                            let reason = format!("directory `{name}` was never listed");
+                            ParseError::at(text, name, reason).at_line(i + 1)
+                        })?;
+                    path.push(index);
+                }
+                Line::Ls => {}
+                Line::Dir(name) => working_dir.directories.push(Directory {
+                    name,
+                    directories: vec![],
+                    files: vec![],
+                }),
+                Line::File(size) => working_dir.files.push(File { size }),
+            }
+        }
+
+        let mut sizes = vec![];
+        root_dir.record_sizes(&mut sizes);
+        Ok(sizes)
+    }
+
+    fn part_one(sizes: &Self::Input<'_>) -> Self::PartOne {
+        sizes.iter().filter(|s| s < &&100000).sum()
+    }
+
+    fn part_two(sizes: &Self::Input<'_>) -> Self::PartTwo {
+        let total = *sizes.iter().max().unwrap();
+        let empty = 70000000_u64.saturating_sub(total);
+        let required = 30000000_u64.saturating_sub(empty);
+
+        sizes
+            .iter()
+            .filter(|s| s > &&required)
+            .min()
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{rng::Rng, Generate};
+
+    #[test]
+    fn day07_a() {
+        let input = Day07::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day07::part_one(&input), 95437);
+    }
+
+    #[test]
+    fn day07_b() {
+        let input = Day07::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day07::part_two(&input), 24933642);
+    }
+
+    #[test]
+    fn day07_invalid() {
+        let e = Day07::parse("$ cd /\n$ ls\n12x a.txt").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(3), 1));
+
+        let e = Day07::parse("$ cd /\n$ ls\n99999999999 a.txt").unwrap_err();
+        assert_eq!(e.line(), Some(3));
+
+        let e = Day07::parse("$ cd /\n$ rm -rf a").unwrap_err();
+        assert_eq!((e.line(), e.reason()), (Some(2), "unknown command `rm`"));
+
+        let e = Day07::parse("$ cd").unwrap_err();
+        assert_eq!(e.line(), Some(1));
+
+        let e = Day07::parse("$ cd /\n$ ls\ndir a\n$ cd b").unwrap_err();
+        assert_eq!(
+            (e.line(), e.column(), e.reason()),
+            (Some(4), 6, "directory `b` was never listed")
+        );
+        // Directories are only known where they were listed.
+        let e = Day07::parse("$ ls\ndir a\ndir b\n$ cd a\n$ cd b").unwrap_err();
+        assert_eq!(e.line(), Some(5));
+    }
+
+    #[test]
+    fn day07_large_directory() {
+        // Found by fuzzing, the total used to overflow.
+        let sizes = Day07::parse("2147483648 s\n2147483648 0").unwrap();
+        assert_eq!(sizes, vec![4294967296]);
+    }
+
+    #[test]
+    fn day07_generated() {
+        for seed in 0..10 {
+            let input = Day07::generate(&mut Rng::new(seed), Day07::SIZE);
+            let sizes = Day07::parse(&input).unwrap();
+            assert!((45_000_000 - Day07::SIZE as u64..=65_000_000).contains(&sizes[0]));
+            assert!(Day07::part_two(&sizes) > 0);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::Day08;
+use aoc_common::{rng::Rng, Generate};
+
+impl Generate for Day08 {
+    const SIZE: usize = 99;
+
+    // A square grid of trees, `size` on each side.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                (0..size.max(1))
+                    .map(|_| char::from_digit(rng.range(0..=9), 10).unwrap())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
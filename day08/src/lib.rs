@@ -0,0 +1,169 @@
+mod generator;
+
+use aoc_common::{direction::Dir4, grid::Grid, ParseError, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Grid<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse(input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| format!("invalid tree height `{c}`"))
+        })
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Self::PartOne {
+        let (width, height) = (grid.width(), grid.height());
+        let mut visible = Grid::filled(width, height, false);
+
+        // Walk every line of trees from both of its ends, marking the trees taller than every
+        // one before them.
+        let starts = (0..height)
+            .flat_map(|y| [((0, y), Dir4::Right), ((width - 1, y), Dir4::Left)])
+            .chain((0..width).flat_map(|x| [((x, 0), Dir4::Down), ((x, height - 1), Dir4::Up)]));
+
+        for (start, dir) in starts {
+            let mut tallest = grid[start];
+            visible[start] = true;
+
+            for (pos, &tree) in grid.ray(start, dir) {
+                if tree > tallest {
+                    visible[pos] = true;
+                    tallest = tree;
+                }
+            }
+        }
+
+        visible.iter().filter(|(_, &v)| v).count() as u32
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Self::PartTwo {
+        // Counts trees up to and including the first one blocking the view.
+        fn viewing_distance(tree: u32, line: impl Iterator<Item = u32>) -> u32 {
+            let mut distance = 0;
+            for t in line {
+                distance += 1;
+                if t >= tree {
+                    break;
+                }
+            }
+            distance
+        }
+
+        // Score is 0 on the borders, some viewing distance being.
+        grid.positions()
+            .map(|pos| {
+                Dir4::ALL
+                    .into_iter()
+                    .map(|dir| viewing_distance(grid[pos], grid.ray(pos, dir).map(|(_, &t)| t)))
+                    .product()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+// Looks from every tree towards the borders, to compare the sweeps against.
+#[cfg(test)]
+mod reference {
+    use super::*;
+
+    // Only counts the borders right for grids at least two trees wide and high.
+    pub fn part_one(grid: &Grid<u32>) -> u32 {
+        let (width, height) = (grid.width(), grid.height());
+        let mut visible = (2 * width + 2 * height - 4) as u32;
+
+        // Ignore borders.
+        for y in 1..height - 1 {
+            for x in 1..width - 1 {
+                let tree = grid[(x, y)];
+                let seen_from = |dir: Dir4| grid.ray((x, y), dir).all(|(_, &t)| t < tree);
+
+                if Dir4::ALL.into_iter().any(seen_from) {
+                    visible += 1;
+                }
+            }
+        }
+
+        visible
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{
+        check::{check, check_with},
+        rng::Rng,
+        Generate,
+    };
+
+    #[test]
+    fn day08_a() {
+        let input = Day08::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day08::part_one(&input), 21);
+    }
+
+    #[test]
+    fn day08_b() {
+        let input = Day08::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day08::part_two(&input), 8);
+    }
+
+    #[test]
+    fn day08_invalid() {
+        let e = Day08::parse("303\n2a5\n653").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 2));
+
+        let e = Day08::parse("303\n25\n653").unwrap_err();
+        assert_eq!(e.line(), Some(2));
+
+        assert!(Day08::parse("").is_err());
+    }
+
+    #[test]
+    fn day08_generated() {
+        for seed in 0..10 {
+            let input = Day08::generate(&mut Rng::new(seed), 20);
+            let grid = Day08::parse(&input).unwrap();
+            assert_eq!((grid.width(), grid.height()), (20, 20));
+            assert!(Day08::part_one(&grid) >= 76);
+        }
+    }
+
+    #[test]
+    fn day08_reference() {
+        // Rectangles at least two trees wide and high, the reference counting borders that way.
+        let forest = |rng: &mut Rng, size: usize| {
+            let width = rng.range(2..=size.max(2));
+            (0..size.max(2))
+                .map(|_| {
+                    (0..width)
+                        .map(|_| char::from_digit(rng.range(0..=9), 10).unwrap())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        check_with::<Day08>(forest, 2..=30, |grid| {
+            Day08::part_one(grid) == reference::part_one(grid)
+        });
+    }
+
+    #[test]
+    fn day08_borders_are_visible() {
+        assert_eq!(Day08::part_one(&Day08::parse("303\n215").unwrap()), 6);
+
+        check::<Day08>(1..=50, |grid| {
+            let (height, width) = (grid.height() as u32, grid.width() as u32);
+            Day08::part_one(grid) >= (2 * height + 2 * width).saturating_sub(4).max(1)
+        });
+    }
+}
@@ -0,0 +1,20 @@
+use crate::Day09;
+use aoc_common::{rng::Rng, Generate};
+
+impl Generate for Day09 {
+    const SIZE: usize = 2000;
+
+    // `size` motions of the head, up to 20 steps each.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.choose(&['U', 'D', 'L', 'R']),
+                    rng.range(1..=20)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,191 @@
+mod generator;
+
+use aoc_common::{
+    direction::Dir4, parse_lines, point::Point, stream, stream::StreamError, ParseError, Solution,
+    Stream,
+};
+use std::{collections::HashSet, io::BufRead, str::FromStr};
+
+/// A motion of the head of the rope.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Motion {
+    direction: Dir4,
+    steps: u32,
+}
+
+impl FromStr for Motion {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        match fields.as_slice() {
+            [direction, distance] => {
+                let value: i32 = distance
+                    .parse()
+                    .map_err(|e| ParseError::at(s, distance, format!("invalid distance: {e}")))?;
+                if value < 0 {
+                    return Err(ParseError::at(s, distance, "negative distance"));
+                }
+                let direction = match direction.to_uppercase().as_str() {
+                    "U" => Dir4::Up,
+                    "D" => Dir4::Down,
+                    "L" => Dir4::Left,
+                    "R" => Dir4::Right,
+                    _ => {
+                        return Err(ParseError::at(
+                            s,
+                            direction,
+                            "unknown direction, expected one of U, D, L or R",
+                        ))
+                    }
+                };
+                Ok(Motion {
+                    direction,
+                    steps: value as u32,
+                })
+            }
+            _ => Err(ParseError::new(s, "expected `<direction> <distance>`")),
+        }
+    }
+}
+
+// A rope pulled by its head, one step at a time, recording the positions visited by its tail.
+struct Rope {
+    knots: Vec<Point<i32>>,
+    visited: HashSet<Point<i32>>,
+}
+
+impl Rope {
+    fn new(knots: usize) -> Self {
+        Self {
+            knots: vec![Point::ORIGIN; knots],
+            visited: HashSet::from([Point::ORIGIN]),
+        }
+    }
+
+    fn pull(&mut self, motion: Motion) {
+        let rope = &mut self.knots;
+        for _ in 0..motion.steps {
+            rope[0] = rope[0].step(motion.direction);
+
+            for i in 1..rope.len() {
+                // A knot only moves when no longer touching the one before it, one step towards it.
+                let gap = rope[i - 1] - rope[i];
+                if gap.chebyshev_length() > 1 {
+                    rope[i] += gap.signum();
+                }
+            }
+            self.visited.extend(rope.last());
+        }
+    }
+
+    fn visited(&self) -> u32 {
+        self.visited.len() as u32
+    }
+}
+
+fn simulate(motions: &[Motion], knots: usize) -> u32 {
+    let mut rope = Rope::new(knots);
+    for &motion in motions {
+        rope.pull(motion);
+    }
+    rope.visited()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Motion>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_one(motions: &Self::Input<'_>) -> Self::PartOne {
+        simulate(motions, 2)
+    }
+
+    fn part_two(motions: &Self::Input<'_>) -> Self::PartTwo {
+        simulate(motions, 10)
+    }
+}
+
+// Only holds the ropes and the positions their tails visited, not the motions.
+impl Stream for Day09 {
+    fn solve_reader(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), StreamError> {
+        let (mut short, mut long) = (Rope::new(2), Rope::new(10));
+        stream::lines(reader, |line| {
+            let motion = line.parse()?;
+            short.pull(motion);
+            long.pull(motion);
+            Ok(())
+        })?;
+
+        Ok((short.visited(), long.visited()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{rng::Rng, Generate};
+    use std::io::BufReader;
+
+    #[test]
+    fn day09_a() {
+        let input = Day09::parse(include_str!("input_data/test_a.txt")).unwrap();
+        assert_eq!(Day09::part_one(&input), 13);
+    }
+
+    #[test]
+    fn day09_b() {
+        let input = Day09::parse(include_str!("input_data/test_a.txt")).unwrap();
+        assert_eq!(Day09::part_two(&input), 1);
+
+        let input = Day09::parse(include_str!("input_data/test_b.txt")).unwrap();
+        assert_eq!(Day09::part_two(&input), 36);
+    }
+
+    #[test]
+    fn day09_invalid() {
+        let e = Day09::parse("R 4\nX 4").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 1));
+
+        let e = Day09::parse("R 4\nU 4.5").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 3));
+
+        // Found by fuzzing.
+        let e = Day09::parse("L -2147483648").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(1), 3));
+        assert!(Day09::parse("D -2147483648").is_err());
+    }
+
+    #[test]
+    fn day09_stream() {
+        // The larger example with `\r\n` line endings, read 2 bytes at a time so that its
+        // two-digit steps are split across reads.
+        let example = include_str!("input_data/test_b.txt").replace('\n', "\r\n");
+        let reader = BufReader::with_capacity(2, example.as_bytes());
+        assert_eq!(Day09::solve_reader(reader).unwrap(), (88, 36));
+
+        let e = Day09::solve_reader("R 4\nU 12\nX 4".as_bytes()).unwrap_err();
+        assert!(matches!(e, StreamError::Parse(e) if e.line() == Some(3)));
+    }
+
+    #[test]
+    fn day09_generated() {
+        // Motions of 1 to 20 steps, in every direction.
+        for seed in 0..10 {
+            let input = Day09::generate(&mut Rng::new(seed), Day09::SIZE);
+            let motions = Day09::parse(&input).unwrap();
+            assert_eq!(motions.len(), Day09::SIZE);
+            assert!(motions.iter().all(|m| (1..=20).contains(&m.steps)));
+            for direction in [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right] {
+                assert!(motions.iter().any(|m| m.direction == direction));
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::Day10;
+use aoc_common::{rng::Rng, Generate};
+
+impl Generate for Day10 {
+    const SIZE: usize = 140;
+
+    // `size` instructions, more if needed to draw the 240 pixels of the screen. Short programs
+    // run for exactly 240 cycles, like the puzzle's.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut lines = vec![];
+        let mut cycles = 0;
+
+        while lines.len() < size || cycles < 240 {
+            // An `addx` would run one cycle past the screen.
+            if cycles == 239 || rng.chance(0.3) {
+                lines.push("noop".to_string());
+                cycles += 1;
+            } else {
+                lines.push(format!("addx {}", rng.range(-20..=20)));
+                cycles += 2;
+            }
+        }
+
+        lines.join("\n")
+    }
+}
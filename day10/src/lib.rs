@@ -0,0 +1,267 @@
+mod generator;
+
+use aoc_common::{parse_lines, stream, stream::StreamError, ParseError, Rows, Solution, Stream};
+use std::{io::BufRead, str::FromStr};
+
+// Wide enough for programs streamed without end: reaching the limits of an `i128` would take
+// far more cycles than a `u64` counts.
+#[derive(Clone, Copy, Debug)]
+struct State {
+    x: i128,
+    tick: u64,
+    power: i128,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            x: 1,
+            tick: 0,
+            power: 0,
+        }
+    }
+
+    fn tick(&mut self) {
+        self.tick += 1;
+
+        if self.tick % 40 == 20 {
+            self.power += i128::from(self.tick) * self.x;
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    NoOp,
+    AddX { val: i32 },
+    RawAddX { val: i32 },
+}
+
+impl Instruction {
+    fn process(self, state: &mut State) -> Option<Instruction> {
+        state.tick();
+
+        match self {
+            Self::NoOp => None,
+            Self::AddX { val } => Some(Self::RawAddX { val }),
+            Self::RawAddX { val } => {
+                state.x += i128::from(val);
+                None
+            }
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fragments = s.split_whitespace();
+        let i = fragments.next();
+        let args: Vec<_> = fragments.collect();
+
+        match (i, &args[..]) {
+            (Some("noop"), []) => Ok(Instruction::NoOp),
+            (Some("addx"), [val]) => Ok(Instruction::AddX {
+                val: val
+                    .parse::<i32>()
+                    .map_err(|e| ParseError::at(s, val, format!("invalid value: {e}")))?,
+            }),
+            (Some("noop"), [extra, ..]) => Err(ParseError::at(s, extra, "noop takes no argument")),
+            (Some("addx"), _) => Err(ParseError::new(s, "addx takes a single value")),
+            (Some(i), _) => Err(ParseError::at(s, i, "unknown instruction")),
+            (None, _) => Err(ParseError::new(s, "missing instruction")),
+        }
+    }
+}
+
+const WIDTH: usize = 40;
+const PIXELS: usize = WIDTH * 6;
+
+// Runs the program, drawing a pixel of the screen every cycle. The screen wraps around every
+// 240 cycles, drawing over the previous frame.
+struct Crt {
+    state: State,
+    pixels: [bool; PIXELS],
+}
+
+impl Crt {
+    fn new() -> Self {
+        Self {
+            state: State::new(),
+            pixels: [false; PIXELS],
+        }
+    }
+
+    fn draw(&mut self) {
+        let pixel = (self.state.tick % PIXELS as u64) as usize;
+        let carret = (pixel % WIDTH) as i128;
+        let pos = self.state.x;
+
+        self.pixels[pixel] = (pos - 1..=pos + 1).contains(&carret);
+    }
+
+    fn execute(&mut self, instruction: Instruction) {
+        self.draw();
+        let mut r = instruction.process(&mut self.state);
+
+        while let Some(instruction) = r {
+            self.draw();
+            r = instruction.process(&mut self.state);
+        }
+    }
+
+    // Pixels drawn so far, the whole screen once it wrapped around.
+    fn screen(&self) -> Rows {
+        let drawn = self.state.tick.min(PIXELS as u64) as usize;
+        let rows = self.pixels[..drawn]
+            .chunks(WIDTH)
+            .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect());
+        Rows(rows.collect())
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Instruction>;
+    type PartOne = i128;
+    type PartTwo = Rows;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_one(instructions: &Self::Input<'_>) -> Self::PartOne {
+        let mut state = State::new();
+
+        for instruction in instructions {
+            let mut r = instruction.process(&mut state);
+
+            while let Some(instruction) = r {
+                r = instruction.process(&mut state);
+            }
+        }
+
+        state.power
+    }
+
+    fn part_two(instructions: &Self::Input<'_>) -> Self::PartTwo {
+        let mut crt = Crt::new();
+        for &instruction in instructions {
+            crt.execute(instruction);
+        }
+        crt.screen()
+    }
+}
+
+// Only holds the screen being drawn, not the program.
+impl Stream for Day10 {
+    fn solve_reader(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), StreamError> {
+        let mut crt = Crt::new();
+        stream::lines(reader, |line| {
+            crt.execute(line.parse()?);
+            Ok(())
+        })?;
+
+        // Drawing runs the same cycles as part one.
+        Ok((crt.state.power, crt.screen()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{rng::Rng, Generate};
+    use std::io::BufReader;
+
+    #[test]
+    fn day10_a() {
+        let input = Day10::parse(include_str!("input_data/test.txt")).unwrap();
+        assert_eq!(Day10::part_one(&input), 13140);
+    }
+
+    #[test]
+    fn day10_b() {
+        let input = Day10::parse(include_str!("input_data/test.txt")).unwrap();
+        let output = r#"
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+        "#
+        .trim();
+        assert_eq!(Day10::part_two(&input).to_string(), output);
+    }
+
+    #[test]
+    fn day10_invalid() {
+        let e = Day10::parse("noop\naddx").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 1));
+
+        let e = Day10::parse("noop\naddx 1O").unwrap_err();
+        assert_eq!((e.line(), e.column()), (Some(2), 6));
+
+        let e = Day10::parse("noop\njmp 4").unwrap_err();
+        assert_eq!(e.reason(), "unknown instruction");
+    }
+
+    #[test]
+    fn day10_stream() {
+        // A buffer of 3 bytes splits instructions and their values across reads.
+        let example = include_str!("input_data/test.txt");
+        let instructions = Day10::parse(example).unwrap();
+        assert_eq!(
+            Day10::solve_reader(BufReader::with_capacity(3, example.as_bytes())).unwrap(),
+            (
+                Day10::part_one(&instructions),
+                Day10::part_two(&instructions)
+            )
+        );
+
+        let e = Day10::solve_reader("noop\naddx 1\naddx".as_bytes()).unwrap_err();
+        assert!(matches!(e, StreamError::Parse(e) if e.line() == Some(3)));
+    }
+
+    #[test]
+    fn day10_large_values() {
+        // The register goes past an `i32` by the 20th cycle.
+        let program = format!("{}{}", "addx 2147483647\n".repeat(4), "noop\n".repeat(12));
+        let (power, _) = Day10::solve_reader(program.as_bytes()).unwrap();
+        assert_eq!(power, 20 * (1 + 4 * 2147483647));
+    }
+
+    #[test]
+    fn day10_generated() {
+        for seed in 0..10 {
+            let input = Day10::generate(&mut Rng::new(seed), 10);
+            let instructions = Day10::parse(&input).unwrap();
+            // Short programs run for exactly the 240 cycles of the screen.
+            let cycles = instructions
+                .iter()
+                .map(|instruction| match instruction {
+                    Instruction::AddX { .. } => 2,
+                    _ => 1,
+                })
+                .sum::<usize>();
+            assert_eq!(cycles, PIXELS);
+        }
+    }
+
+    #[test]
+    fn day10_long_stream() {
+        // Each pair of instructions takes 4 cycles, so every frame draws the same picture.
+        let frame = "addx 38\naddx -38\n".repeat(PIXELS / 4);
+        let (_, expected) = Day10::solve_reader(frame.as_bytes()).unwrap();
+        let program = frame.repeat(1000);
+        let (power, screen) = Day10::solve_reader(program.as_bytes()).unwrap();
+        assert_eq!(screen, expected);
+        assert_eq!(power, Day10::part_one(&Day10::parse(&program).unwrap()));
+
+        let short = Day10::parse("noop\naddx 1").unwrap();
+        assert_eq!(Day10::part_two(&short).to_string(), "###");
+    }
+}